@@ -51,7 +51,9 @@ contract CrossChainBridge is CrossChainBridgeGetters, CrossChainBridgeMessages {
     }
 
     /**
-     * @dev Automatically handles both native (lock) and wrapped (burn) tokens
+     * @dev Automatically handles both native (lock) and wrapped (burn) tokens.
+     * Sends a V2 payload with default options, so the transfer can be refunded
+     * to the caller.
      */
     function sendTokensWithPayload(
         address token,
@@ -59,9 +61,29 @@ contract CrossChainBridge is CrossChainBridgeGetters, CrossChainBridgeMessages {
         uint16 targetChain,
         bytes32 targetRecipient,
         uint32 batchId
+    ) public payable returns (uint64 sequence) {
+        TransferOptions memory options;
+        sequence = sendTokensWithOptions(token, amount, targetChain, targetRecipient, batchId, options);
+    }
+
+    /**
+     * @dev Like `sendTokensWithPayload`, with sender-specified relayer fee cap,
+     * tip, gas drop-off, memo, refund address and app data.
+     */
+    function sendTokensWithOptions(
+        address token,
+        uint256 amount,
+        uint16 targetChain,
+        bytes32 targetRecipient,
+        uint32 batchId,
+        TransferOptions memory options
     ) public payable returns (uint64 sequence) {
         require(amount > 0, "amount must be greater than 0");
         require(targetRecipient != bytes32(0), "invalid recipient");
+        require(
+            options.targetRelayerFee <= _normalizeAmount(amount, token),
+            "relayer fee exceeds amount"
+        );
 
         bytes32 targetContract = getRegisteredEmitter(targetChain);
         require(targetContract != bytes32(0), "target chain not registered");
@@ -73,10 +95,7 @@ contract CrossChainBridge is CrossChainBridgeGetters, CrossChainBridgeMessages {
         _safeTransferFrom(token, msg.sender, address(this), amount);
         IERC20(token).approve(address(bridge), amount);
 
-        bytes memory payload = encodePayload(CrossChainPayload({
-            payloadId: 1,
-            recipient: targetRecipient
-        }));
+        bytes memory payload = _encodeOptions(targetRecipient, options);
 
         sequence = bridge.transferTokensWithPayload{value: wormholeFee}(
             token,
@@ -94,26 +113,34 @@ contract CrossChainBridge is CrossChainBridgeGetters, CrossChainBridgeMessages {
      * @dev Automatically handles both native (unlock) and wrapped (mint) tokens
      */
     function redeemTokensWithPayload(bytes memory encodedVaa) public {
-        (IWormhole.VM memory vm, bool valid, string memory reason) = wormhole().parseAndVerifyVM(encodedVaa);
-        require(valid, reason);
+        IWormhole.VM memory vm;
+        {
+            bool valid;
+            string memory reason;
+            (vm, valid, reason) = wormhole().parseAndVerifyVM(encodedVaa);
+            require(valid, reason);
+        }
 
         require(verifyEmitter(vm), "unknown emitter");
         require(!isTransferRedeemed(vm.hash), "transfer already redeemed");
 
-        ITokenBridge bridge = tokenBridge();
-        bytes memory transferPayload = bridge.completeTransferWithPayload(encodedVaa);
-
-        ITokenBridge.TransferWithPayload memory transfer = bridge.parseTransferWithPayload(transferPayload);
-
-        CrossChainPayload memory payload = decodePayload(transfer.payload);
-        require(payload.payloadId == 1, "invalid payload ID");
+        ITokenBridge.TransferWithPayload memory transfer;
+        {
+            ITokenBridge bridge = tokenBridge();
+            bytes memory transferPayload = bridge.completeTransferWithPayload(encodedVaa);
+            transfer = bridge.parseTransferWithPayload(transferPayload);
+        }
 
-        address recipient = _truncateAddress(payload.recipient);
         address tokenAddress = _getLocalTokenAddress(transfer);
         uint256 amount = _denormalizeAmount(transfer.amount, tokenAddress);
+        (address recipient, uint256 relayerAmount) = _payoutOf(transfer.payload, tokenAddress, amount);
+        amount -= relayerAmount;
 
         setTransferRedeemed(vm.hash);
 
+        if (relayerAmount > 0) {
+            _safeTransfer(tokenAddress, msg.sender, relayerAmount);
+        }
         _safeTransfer(tokenAddress, recipient, amount);
 
         emit TokensRedeemed(
@@ -155,6 +182,68 @@ contract CrossChainBridge is CrossChainBridgeGetters, CrossChainBridgeMessages {
         return getRegisteredEmitter(vm.emitterChainId) == vm.emitterAddress;
     }
 
+    /**
+     * @dev V2 payload of an outbound transfer from `msg.sender`.
+     */
+    function _encodeOptions(
+        bytes32 targetRecipient,
+        TransferOptions memory options
+    ) internal view returns (bytes memory) {
+        CrossChainPayloadV2 memory payload;
+        payload.payloadId = PAYLOAD_ID_HELLO_V2;
+        payload.recipient = targetRecipient;
+        payload.recipientKind = options.recipientKind;
+        payload.sender = bytes32(uint256(uint160(msg.sender)));
+        payload.maxRelayerFee = options.maxRelayerFee;
+        payload.targetRelayerFee = options.targetRelayerFee;
+        payload.gasDropoff = options.gasDropoff;
+        payload.memo = options.memo;
+        payload.refundAddress = options.refundAddress;
+        payload.appData = options.appData;
+        return encodePayloadV2(payload);
+    }
+
+    /**
+     * @dev Recipient of a transfer payload and the relayer's share of `amount`.
+     * A V2 payload redeemed by anyone but its recipient pays the relayer the
+     * configured relayer fee, capped at the payload's maxRelayerFee unless it
+     * is zero (the default), plus the sender's tip. EVM recipients are always wallets, and gas drop-offs are
+     * only delivered on Solana. A refund of an undeliverable transfer pays
+     * its recipient in full.
+     */
+    function _payoutOf(
+        bytes memory encoded,
+        address token,
+        uint256 amount
+    ) internal view returns (address recipient, uint256 relayerAmount) {
+        uint8 payloadId = encoded.toUint8(0);
+        if (payloadId == PAYLOAD_ID_HELLO) {
+            return (_truncateAddress(decodePayload(encoded).recipient), 0);
+        }
+        if (payloadId == PAYLOAD_ID_REFUND) {
+            return (_truncateAddress(decodeRefundPayload(encoded).recipient), 0);
+        }
+        require(payloadId == PAYLOAD_ID_HELLO_V2, "invalid payload ID");
+
+        CrossChainPayloadV2 memory payload = decodePayloadV2(encoded);
+        recipient = _truncateAddress(payload.recipient);
+        if (msg.sender == recipient) {
+            return (recipient, 0);
+        }
+
+        uint256 fee = relayerFeePrecision() == 0 ? 0 : amount * relayerFee() / relayerFeePrecision();
+        if (payload.maxRelayerFee != 0) {
+            uint256 maxFee = _denormalizeAmount(payload.maxRelayerFee, token);
+            if (fee > maxFee) {
+                fee = maxFee;
+            }
+        }
+        relayerAmount = fee + _denormalizeAmount(payload.targetRelayerFee, token);
+        if (relayerAmount > amount) {
+            relayerAmount = amount;
+        }
+    }
+
     function _truncateAddress(bytes32 addressBytes) internal pure returns (address) {
         return address(uint160(uint256(addressBytes)));
     }
@@ -180,6 +269,17 @@ contract CrossChainBridge is CrossChainBridgeGetters, CrossChainBridgeMessages {
         return amount;
     }
 
+    function _normalizeAmount(
+        uint256 amount,
+        address token
+    ) internal view returns (uint256) {
+        uint8 decimals = IERC20Metadata(token).decimals();
+        if (decimals > 8) {
+            amount /= 10 ** (decimals - 8);
+        }
+        return amount;
+    }
+
     function _safeTransfer(address token, address to, uint256 amount) internal {
         bool success = IERC20(token).transfer(to, amount);
         require(success, "transfer failed");
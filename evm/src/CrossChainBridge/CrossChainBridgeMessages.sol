@@ -7,6 +7,14 @@ import {BytesLib} from "wormhole-solidity-sdk/testing/helpers/BytesLib.sol";
 contract CrossChainBridgeMessages is CrossChainBridgeStructs {
     using BytesLib for bytes;
 
+    uint8 public constant PAYLOAD_ID_HELLO = 1;
+    /// @dev Payload ID 2 is reserved and never emitted.
+    uint8 public constant PAYLOAD_ID_HELLO_V2 = 3;
+    uint8 public constant PAYLOAD_ID_REFUND = 4;
+
+    /// @dev Maximum length of the app data carried by a V2 payload.
+    uint256 public constant APP_DATA_MAX_LENGTH = 256;
+
     /**
      * @dev Format: [payloadId (1 byte)][recipient (32 bytes)]
      */
@@ -35,4 +43,117 @@ contract CrossChainBridgeMessages is CrossChainBridgeStructs {
 
         require(index == encoded.length, "invalid payload length");
     }
+
+    /**
+     * @dev Frozen format, integers big-endian:
+     * [payloadId = 3 (1)][recipient (32)][recipientKind (1)][sender (32)]
+     * [maxRelayerFee (8)][targetRelayerFee (8)][gasDropoff (8)][memo (32)]
+     * [refundAddress (32)][appData length (2)][appData (<= 256)]
+     * Any layout change takes a new payload ID.
+     */
+    function encodePayloadV2(
+        CrossChainPayloadV2 memory payload
+    ) public pure returns (bytes memory encoded) {
+        require(payload.payloadId == PAYLOAD_ID_HELLO_V2, "invalid payload ID");
+        require(payload.appData.length <= APP_DATA_MAX_LENGTH, "app data too long");
+
+        encoded = abi.encodePacked(
+            payload.payloadId,
+            payload.recipient,
+            payload.recipientKind,
+            payload.sender,
+            payload.maxRelayerFee,
+            payload.targetRelayerFee,
+            payload.gasDropoff
+        );
+        encoded = abi.encodePacked(
+            encoded,
+            payload.memo,
+            payload.refundAddress,
+            uint16(payload.appData.length),
+            payload.appData
+        );
+    }
+
+    /**
+     * @dev Expects the format of `encodePayloadV2`.
+     */
+    function decodePayloadV2(
+        bytes memory encoded
+    ) public pure returns (CrossChainPayloadV2 memory payload) {
+        uint256 index = 0;
+
+        payload.payloadId = encoded.toUint8(index);
+        index += 1;
+        require(payload.payloadId == PAYLOAD_ID_HELLO_V2, "invalid payload ID");
+
+        payload.recipient = encoded.toBytes32(index);
+        index += 32;
+
+        payload.recipientKind = encoded.toUint8(index);
+        index += 1;
+
+        payload.sender = encoded.toBytes32(index);
+        index += 32;
+
+        payload.maxRelayerFee = encoded.toUint64(index);
+        index += 8;
+
+        payload.targetRelayerFee = encoded.toUint64(index);
+        index += 8;
+
+        payload.gasDropoff = encoded.toUint64(index);
+        index += 8;
+
+        payload.memo = encoded.toBytes32(index);
+        index += 32;
+
+        payload.refundAddress = encoded.toBytes32(index);
+        index += 32;
+
+        uint256 appDataLength = encoded.toUint16(index);
+        index += 2;
+        require(appDataLength <= APP_DATA_MAX_LENGTH, "app data too long");
+
+        payload.appData = encoded.slice(index, appDataLength);
+        index += appDataLength;
+
+        require(index == encoded.length, "invalid payload length");
+    }
+
+    /**
+     * @dev Format: [payloadId = 4 (1)][recipient (32)][sequence (8)]
+     */
+    function encodeRefundPayload(
+        CrossChainRefundPayload memory payload
+    ) public pure returns (bytes memory encoded) {
+        require(payload.payloadId == PAYLOAD_ID_REFUND, "invalid payload ID");
+
+        encoded = abi.encodePacked(
+            payload.payloadId,
+            payload.recipient,
+            payload.sequence
+        );
+    }
+
+    /**
+     * @dev Expects the format of `encodeRefundPayload`.
+     */
+    function decodeRefundPayload(
+        bytes memory encoded
+    ) public pure returns (CrossChainRefundPayload memory payload) {
+        uint256 index = 0;
+
+        payload.payloadId = encoded.toUint8(index);
+        index += 1;
+        require(payload.payloadId == PAYLOAD_ID_REFUND, "invalid payload ID");
+
+        payload.recipient = encoded.toBytes32(index);
+        index += 32;
+
+        payload.sequence = encoded.toUint64(index);
+        index += 8;
+
+        require(index == encoded.length, "invalid payload length");
+    }
 }
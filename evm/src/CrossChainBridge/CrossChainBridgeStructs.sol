@@ -2,8 +2,45 @@
 pragma solidity ^0.8.19;
 
 contract CrossChainBridgeStructs {
+    /// @dev Payload ID 1: recipient only.
     struct CrossChainPayload {
         uint8 payloadId;
         bytes32 recipient;
     }
+
+    /// @dev Payload ID 3, shared with the Solana program. Relayer fees are
+    /// normalized (8 decimals); the gas drop-off is in the target chain's
+    /// smallest unit. Recipient kinds other than a wallet only apply on Solana.
+    struct CrossChainPayloadV2 {
+        uint8 payloadId;
+        bytes32 recipient;
+        uint8 recipientKind;
+        bytes32 sender;
+        uint64 maxRelayerFee;
+        uint64 targetRelayerFee;
+        uint64 gasDropoff;
+        bytes32 memo;
+        bytes32 refundAddress;
+        bytes appData;
+    }
+
+    /// @dev Payload ID 4, shared with the Solana program: a transfer the
+    /// target chain could not deliver, sent back to `recipient` on its origin
+    /// chain. `sequence` is the Token Bridge sequence of the refunded transfer.
+    struct CrossChainRefundPayload {
+        uint8 payloadId;
+        bytes32 recipient;
+        uint64 sequence;
+    }
+
+    /// @dev Sender-specified settings of an outbound transfer.
+    struct TransferOptions {
+        uint8 recipientKind;
+        uint64 maxRelayerFee;
+        uint64 targetRelayerFee;
+        uint64 gasDropoff;
+        bytes32 memo;
+        bytes32 refundAddress;
+        bytes appData;
+    }
 }
@@ -23,6 +23,12 @@ contract CrossChainMessenger is CrossChainMessengerGetters, CrossChainMessengerM
         bytes payload
     );
 
+    event ExecuteSent(
+        address indexed sender,
+        uint64 indexed nonce,
+        uint64 sequence
+    );
+
     event EmitterRegistered(uint16 indexed chainId, bytes32 emitterAddress);
 
     constructor(
@@ -56,7 +62,7 @@ contract CrossChainMessenger is CrossChainMessengerGetters, CrossChainMessengerM
         require(msg.value >= wormholeFee, "insufficient fee");
 
         bytes memory encodedMessage = encodeMessage(
-            MessengerPayload({payloadId: 1, payload: payload})
+            MessengerPayload({payloadId: PAYLOAD_ID_MESSAGE, payload: payload})
         );
 
         sequence = wh.publishMessage{value: wormholeFee}(
@@ -68,6 +74,50 @@ contract CrossChainMessenger is CrossChainMessengerGetters, CrossChainMessengerM
         emit MessageSent(chainId(), sequence, payload);
     }
 
+    /**
+     * @notice Have the Solana messenger execute an instruction signed by the
+     * caller's remote signer PDA
+     * @dev The caller is the sender and gets the next of its nonces, so
+     * nobody can act for another sender's PDA. A failed execution does not
+     * hold up later nonces.
+     * @param computeUnits Compute units the relayer must leave for the instruction
+     * @param programId Target program, which must be an execution target on Solana
+     * @param accounts Accounts of the instruction
+     * @param data Instruction data
+     * @return sequence Wormhole message sequence number
+     */
+    function sendExecute(
+        uint32 computeUnits,
+        bytes32 programId,
+        RemoteAccountMeta[] memory accounts,
+        bytes memory data
+    ) public payable returns (uint64 sequence) {
+        IWormhole wh = wormhole();
+        uint256 wormholeFee = wh.messageFee();
+        require(msg.value >= wormholeFee, "insufficient fee");
+
+        uint64 nonce = useExecuteNonce(msg.sender);
+        bytes memory encodedMessage = encodeExecute(
+            ExecutePayload({
+                payloadId: PAYLOAD_ID_EXECUTE,
+                sender: bytes32(uint256(uint160(msg.sender))),
+                nonce: nonce,
+                computeUnits: computeUnits,
+                programId: programId,
+                accounts: accounts,
+                data: data
+            })
+        );
+
+        sequence = wh.publishMessage{value: wormholeFee}(
+            0, // batchId (nonce)
+            encodedMessage,
+            wormholeFinality()
+        );
+
+        emit ExecuteSent(msg.sender, nonce, sequence);
+    }
+
     /**
      * @notice Receive and verify a message from another chain
      * @param encodedVaa Verified Wormhole message (VAA)
@@ -32,4 +32,8 @@ contract CrossChainMessengerGetters is CrossChainMessengerSetters {
     function isMessageConsumed(bytes32 hash_) public view returns (bool) {
         return _state.consumedMessages[hash_];
     }
+
+    function nextExecuteNonce(address sender_) public view returns (uint64) {
+        return _state.executeNonces[sender_];
+    }
 }
@@ -7,6 +7,13 @@ import {CrossChainMessengerStructs} from "./CrossChainMessengerStructs.sol";
 contract CrossChainMessengerMessages is CrossChainMessengerStructs {
     using BytesLib for bytes;
 
+    uint8 public constant PAYLOAD_ID_MESSAGE = 1;
+    uint8 public constant PAYLOAD_ID_EXECUTE = 2;
+
+    /// @dev Limits of an Execute payload, as enforced by the Solana program.
+    uint256 public constant EXECUTE_MAX_ACCOUNTS = 32;
+    uint256 public constant EXECUTE_MAX_DATA_LENGTH = 1024;
+
     function encodeMessage(
         MessengerPayload memory parsedMessage
     ) public pure returns (bytes memory encodedMessage) {
@@ -23,7 +30,7 @@ contract CrossChainMessengerMessages is CrossChainMessengerStructs {
         uint256 index = 0;
 
         parsedMessage.payloadId = encodedMessage.toUint8(index);
-        require(parsedMessage.payloadId == 1, "invalid payloadId");
+        require(parsedMessage.payloadId == PAYLOAD_ID_MESSAGE, "invalid payloadId");
         index += 1;
 
         uint256 payloadLength = encodedMessage.toUint16(index);
@@ -34,4 +41,90 @@ contract CrossChainMessengerMessages is CrossChainMessengerStructs {
 
         require(index == encodedMessage.length, "invalid message length");
     }
+
+    /**
+     * @dev Format, integers big-endian:
+     * [payloadId = 2 (1)][sender (32)][nonce (8)][computeUnits (4)]
+     * [programId (32)][account count (1)]
+     * [accounts: pubkey (32), isSigner (1), isWritable (1)]
+     * [data length (2)][data (<= 1024)]
+     */
+    function encodeExecute(
+        ExecutePayload memory parsedMessage
+    ) public pure returns (bytes memory encodedMessage) {
+        require(parsedMessage.payloadId == PAYLOAD_ID_EXECUTE, "invalid payloadId");
+        require(parsedMessage.accounts.length <= EXECUTE_MAX_ACCOUNTS, "too many accounts");
+        require(parsedMessage.data.length <= EXECUTE_MAX_DATA_LENGTH, "data too long");
+
+        encodedMessage = abi.encodePacked(
+            parsedMessage.payloadId,
+            parsedMessage.sender,
+            parsedMessage.nonce,
+            parsedMessage.computeUnits,
+            parsedMessage.programId,
+            uint8(parsedMessage.accounts.length)
+        );
+        for (uint256 i = 0; i < parsedMessage.accounts.length; ++i) {
+            RemoteAccountMeta memory meta = parsedMessage.accounts[i];
+            encodedMessage = abi.encodePacked(
+                encodedMessage,
+                meta.pubkey,
+                meta.isSigner,
+                meta.isWritable
+            );
+        }
+        encodedMessage = abi.encodePacked(
+            encodedMessage,
+            uint16(parsedMessage.data.length),
+            parsedMessage.data
+        );
+    }
+
+    /**
+     * @dev Expects the format of `encodeExecute`.
+     */
+    function decodeExecute(
+        bytes memory encodedMessage
+    ) public pure returns (ExecutePayload memory parsedMessage) {
+        uint256 index = 0;
+
+        parsedMessage.payloadId = encodedMessage.toUint8(index);
+        require(parsedMessage.payloadId == PAYLOAD_ID_EXECUTE, "invalid payloadId");
+        index += 1;
+
+        parsedMessage.sender = encodedMessage.toBytes32(index);
+        index += 32;
+
+        parsedMessage.nonce = encodedMessage.toUint64(index);
+        index += 8;
+
+        parsedMessage.computeUnits = encodedMessage.toUint32(index);
+        index += 4;
+
+        parsedMessage.programId = encodedMessage.toBytes32(index);
+        index += 32;
+
+        uint256 numAccounts = encodedMessage.toUint8(index);
+        index += 1;
+        require(numAccounts <= EXECUTE_MAX_ACCOUNTS, "too many accounts");
+
+        parsedMessage.accounts = new RemoteAccountMeta[](numAccounts);
+        for (uint256 i = 0; i < numAccounts; ++i) {
+            parsedMessage.accounts[i].pubkey = encodedMessage.toBytes32(index);
+            index += 32;
+            parsedMessage.accounts[i].isSigner = encodedMessage.toUint8(index) != 0;
+            index += 1;
+            parsedMessage.accounts[i].isWritable = encodedMessage.toUint8(index) != 0;
+            index += 1;
+        }
+
+        uint256 dataLength = encodedMessage.toUint16(index);
+        index += 2;
+        require(dataLength <= EXECUTE_MAX_DATA_LENGTH, "data too long");
+
+        parsedMessage.data = encodedMessage.slice(index, dataLength);
+        index += dataLength;
+
+        require(index == encodedMessage.length, "invalid message length");
+    }
 }
@@ -24,6 +24,10 @@ contract CrossChainMessengerSetters is CrossChainMessengerState {
         _state.registeredEmitters[chainId_] = emitter_;
     }
 
+    function useExecuteNonce(address sender_) internal returns (uint64 nonce) {
+        nonce = _state.executeNonces[sender_]++;
+    }
+
     function consumeMessage(bytes32 hash_, bytes memory payload_) internal {
         _state.receivedMessages[hash_] = payload_;
         _state.consumedMessages[hash_] = true;
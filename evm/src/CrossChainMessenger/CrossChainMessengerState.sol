@@ -11,6 +11,7 @@ contract CrossChainMessengerStorage {
         mapping(uint16 => bytes32) registeredEmitters;
         mapping(bytes32 => bytes) receivedMessages;
         mapping(bytes32 => bool) consumedMessages;
+        mapping(address => uint64) executeNonces;
     }
 }
 
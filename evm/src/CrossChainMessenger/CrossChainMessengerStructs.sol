@@ -6,4 +6,24 @@ contract CrossChainMessengerStructs {
         uint8 payloadId;
         bytes payload;
     }
+
+    /// @dev Account of a remote instruction, as passed to the Solana program.
+    struct RemoteAccountMeta {
+        bytes32 pubkey;
+        bool isSigner;
+        bool isWritable;
+    }
+
+    /// @dev Payload ID 2, shared with the Solana messenger: an instruction
+    /// executed on Solana by the remote signer PDA of (chain, `sender`). Each
+    /// `nonce` of a sender executes at most once, in any order.
+    struct ExecutePayload {
+        uint8 payloadId;
+        bytes32 sender;
+        uint64 nonce;
+        uint32 computeUnits;
+        bytes32 programId;
+        RemoteAccountMeta[] accounts;
+        bytes data;
+    }
 }
@@ -114,4 +114,173 @@ contract CrossChainBridgeTest is Test {
         bytes memory encoded = messages.encodePayload(payload);
         assertEq(encoded.length, 33); // 1 byte payloadId + 32 bytes recipient
     }
+
+    function _payloadV2(bytes memory appData) internal pure returns (CrossChainBridgeStructs.CrossChainPayloadV2 memory) {
+        return CrossChainBridgeStructs.CrossChainPayloadV2({
+            payloadId: 3,
+            recipient: bytes32(uint256(0x1111111111111111111111111111111111111111111111111111111111111111)),
+            recipientKind: 1,
+            sender: bytes32(uint256(0x2222222222222222222222222222222222222222222222222222222222222222)),
+            maxRelayerFee: 0x0102030405060708,
+            targetRelayerFee: 9,
+            gasDropoff: 10,
+            memo: bytes32(uint256(0x3333333333333333333333333333333333333333333333333333333333333333)),
+            refundAddress: bytes32(uint256(0x4444444444444444444444444444444444444444444444444444444444444444)),
+            appData: appData
+        });
+    }
+
+    function test_EncodeDecodePayloadV2() public view {
+        CrossChainBridgeStructs.CrossChainPayloadV2 memory payload = _payloadV2(hex"deadbeef");
+
+        bytes memory encoded = messages.encodePayloadV2(payload);
+        CrossChainBridgeStructs.CrossChainPayloadV2 memory decoded = messages.decodePayloadV2(encoded);
+
+        assertEq(decoded.payloadId, payload.payloadId);
+        assertEq(decoded.recipient, payload.recipient);
+        assertEq(decoded.recipientKind, payload.recipientKind);
+        assertEq(decoded.sender, payload.sender);
+        assertEq(decoded.maxRelayerFee, payload.maxRelayerFee);
+        assertEq(decoded.targetRelayerFee, payload.targetRelayerFee);
+        assertEq(decoded.gasDropoff, payload.gasDropoff);
+        assertEq(decoded.memo, payload.memo);
+        assertEq(decoded.refundAddress, payload.refundAddress);
+        assertEq(decoded.appData, payload.appData);
+    }
+
+    /// @dev Same bytes as `hello_v2_golden_vector` in the Solana program.
+    function test_PayloadV2GoldenVector() public view {
+        bytes memory expected =
+            hex"031111111111111111111111111111111111111111111111111111111111111111"
+            hex"01"
+            hex"2222222222222222222222222222222222222222222222222222222222222222"
+            hex"0102030405060708"
+            hex"0000000000000009"
+            hex"000000000000000a"
+            hex"3333333333333333333333333333333333333333333333333333333333333333"
+            hex"4444444444444444444444444444444444444444444444444444444444444444"
+            hex"0004deadbeef";
+
+        assertEq(messages.encodePayloadV2(_payloadV2(hex"deadbeef")), expected);
+        assertEq(expected.length, 160);
+    }
+
+    function test_EncodePayloadV2_RevertIfAppDataTooLong() public {
+        CrossChainBridgeStructs.CrossChainPayloadV2 memory payload = _payloadV2(new bytes(257));
+
+        vm.expectRevert("app data too long");
+        messages.encodePayloadV2(payload);
+    }
+
+    function test_EncodePayloadV2_MaxAppData() public view {
+        bytes memory encoded = messages.encodePayloadV2(_payloadV2(new bytes(256)));
+        assertEq(encoded.length, 156 + 256);
+    }
+
+    function test_DecodePayloadV2_RevertIfAppDataTooLong() public {
+        bytes memory encoded = messages.encodePayloadV2(_payloadV2(""));
+        // Declare 257 bytes of app data.
+        encoded[154] = 0x01;
+        encoded[155] = 0x01;
+        bytes memory padded = bytes.concat(encoded, new bytes(257));
+
+        vm.expectRevert("app data too long");
+        messages.decodePayloadV2(padded);
+    }
+
+    function test_DecodePayloadV2_RevertIfTrailingBytes() public {
+        bytes memory encoded = bytes.concat(messages.encodePayloadV2(_payloadV2("")), hex"00");
+
+        vm.expectRevert("invalid payload length");
+        messages.decodePayloadV2(encoded);
+    }
+
+    function test_DecodePayloadV2_RevertIfWrongPayloadId() public {
+        bytes memory encoded = messages.encodePayloadV2(_payloadV2(""));
+        encoded[0] = 0x01;
+
+        vm.expectRevert("invalid payload ID");
+        messages.decodePayloadV2(encoded);
+    }
+
+    /// @dev Same bytes as `refund_golden_vector` in the Solana program.
+    function test_EncodeDecodeRefundPayload() public view {
+        CrossChainBridgeStructs.CrossChainRefundPayload memory payload = CrossChainBridgeStructs.CrossChainRefundPayload({
+            payloadId: 4,
+            recipient: bytes32(uint256(0x1111111111111111111111111111111111111111111111111111111111111111)),
+            sequence: 42
+        });
+
+        bytes memory encoded = messages.encodeRefundPayload(payload);
+        assertEq(
+            encoded,
+            hex"041111111111111111111111111111111111111111111111111111111111111111000000000000002a"
+        );
+
+        CrossChainBridgeStructs.CrossChainRefundPayload memory decoded = messages.decodeRefundPayload(encoded);
+        assertEq(decoded.payloadId, payload.payloadId);
+        assertEq(decoded.recipient, payload.recipient);
+        assertEq(decoded.sequence, payload.sequence);
+    }
+
+    function test_DecodeRefundPayload_RevertIfTrailingBytes() public {
+        bytes memory encoded =
+            hex"041111111111111111111111111111111111111111111111111111111111111111000000000000002a00";
+
+        vm.expectRevert("invalid payload length");
+        messages.decodeRefundPayload(encoded);
+    }
+
+    function test_PayoutOf_ZeroMaxRelayerFeeIsUncapped() public {
+        CrossChainBridgeHarness harness = new CrossChainBridgeHarness(
+            wormhole,
+            tokenBridge,
+            chainId,
+            wormholeFinality
+        );
+        harness.updateRelayerFee(100, 10000); // 1%
+
+        address token = makeAddr("token");
+        vm.etch(token, hex"00");
+        vm.mockCall(token, abi.encodeWithSignature("decimals()"), abi.encode(uint8(18)));
+
+        CrossChainBridgeStructs.CrossChainPayloadV2 memory payload = _payloadV2("");
+        payload.targetRelayerFee = 0;
+
+        // Default options: no cap, the relayer gets the full fee.
+        payload.maxRelayerFee = 0;
+        (, uint256 relayerAmount) = harness.payoutOf(messages.encodePayloadV2(payload), token, 1e18);
+        assertEq(relayerAmount, 1e16);
+
+        // A cap of 1 (normalized) is 1e10 units of 18 decimals.
+        payload.maxRelayerFee = 1;
+        (, relayerAmount) = harness.payoutOf(messages.encodePayloadV2(payload), token, 1e18);
+        assertEq(relayerAmount, 1e10);
+    }
+
+    function test_DecodeRefundPayload_RevertIfWrongPayloadId() public {
+        bytes memory encoded =
+            hex"011111111111111111111111111111111111111111111111111111111111111111000000000000002a";
+
+        vm.expectRevert("invalid payload ID");
+        messages.decodeRefundPayload(encoded);
+    }
+}
+
+/// @dev Exposes the relayer payout of a transfer payload.
+contract CrossChainBridgeHarness is CrossChainBridge {
+    constructor(
+        address wormhole_,
+        address tokenBridge_,
+        uint16 chainId_,
+        uint8 wormholeFinality_
+    ) CrossChainBridge(wormhole_, tokenBridge_, chainId_, wormholeFinality_) {}
+
+    function payoutOf(
+        bytes memory encoded,
+        address token,
+        uint256 amount
+    ) external view returns (address recipient, uint256 relayerAmount) {
+        return _payoutOf(encoded, token, amount);
+    }
 }
@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.19;
+
+import {Test, console} from "forge-std/Test.sol";
+import {IWormhole} from "wormhole-solidity-sdk/interfaces/IWormhole.sol";
+import {CrossChainMessenger} from "../src/CrossChainMessenger/CrossChainMessenger.sol";
+import {CrossChainMessengerStructs} from "../src/CrossChainMessenger/CrossChainMessengerStructs.sol";
+
+contract CrossChainMessengerTest is Test {
+    CrossChainMessenger public messenger;
+
+    address public wormhole = makeAddr("wormhole");
+    uint16 public chainId = 2; // Ethereum
+    uint8 public wormholeFinality = 1;
+
+    function setUp() public {
+        messenger = new CrossChainMessenger(wormhole, chainId, wormholeFinality);
+
+        // Solidity checks for code before calling the mocked Wormhole.
+        vm.etch(wormhole, hex"00");
+        vm.mockCall(
+            wormhole,
+            abi.encodeWithSelector(IWormhole.messageFee.selector),
+            abi.encode(uint256(0))
+        );
+        vm.mockCall(
+            wormhole,
+            abi.encodeWithSelector(IWormhole.publishMessage.selector),
+            abi.encode(uint64(7))
+        );
+    }
+
+    function _execute(
+        bytes memory data
+    ) internal pure returns (CrossChainMessengerStructs.ExecutePayload memory payload) {
+        payload.payloadId = 2;
+        payload.sender = bytes32(uint256(0x1111111111111111111111111111111111111111111111111111111111111111));
+        payload.nonce = 42;
+        payload.computeUnits = 200_000;
+        payload.programId = bytes32(uint256(0x2222222222222222222222222222222222222222222222222222222222222222));
+        payload.accounts = new CrossChainMessengerStructs.RemoteAccountMeta[](1);
+        payload.accounts[0] = CrossChainMessengerStructs.RemoteAccountMeta({
+            pubkey: bytes32(uint256(0x3333333333333333333333333333333333333333333333333333333333333333)),
+            isSigner: true,
+            isWritable: false
+        });
+        payload.data = data;
+    }
+
+    /// @dev Same bytes as `execute_golden_vector` in the Solana program.
+    function test_ExecuteGoldenVector() public view {
+        bytes memory expected =
+            hex"02"
+            hex"1111111111111111111111111111111111111111111111111111111111111111"
+            hex"000000000000002a"
+            hex"00030d40"
+            hex"2222222222222222222222222222222222222222222222222222222222222222"
+            hex"01"
+            hex"33333333333333333333333333333333333333333333333333333333333333330100"
+            hex"0004deadbeef";
+
+        assertEq(messenger.encodeExecute(_execute(hex"deadbeef")), expected);
+        assertEq(expected.length, 118);
+    }
+
+    function test_EncodeDecodeExecute() public view {
+        CrossChainMessengerStructs.ExecutePayload memory payload = _execute(hex"deadbeef");
+
+        CrossChainMessengerStructs.ExecutePayload memory decoded =
+            messenger.decodeExecute(messenger.encodeExecute(payload));
+
+        assertEq(decoded.payloadId, payload.payloadId);
+        assertEq(decoded.sender, payload.sender);
+        assertEq(decoded.nonce, payload.nonce);
+        assertEq(decoded.computeUnits, payload.computeUnits);
+        assertEq(decoded.programId, payload.programId);
+        assertEq(decoded.accounts.length, 1);
+        assertEq(decoded.accounts[0].pubkey, payload.accounts[0].pubkey);
+        assertEq(decoded.accounts[0].isSigner, true);
+        assertEq(decoded.accounts[0].isWritable, false);
+        assertEq(decoded.data, payload.data);
+    }
+
+    function test_EncodeExecute_RevertIfDataTooLong() public {
+        CrossChainMessengerStructs.ExecutePayload memory payload = _execute(new bytes(1025));
+
+        vm.expectRevert("data too long");
+        messenger.encodeExecute(payload);
+    }
+
+    function test_DecodeExecute_RevertIfTrailingBytes() public {
+        bytes memory encoded = bytes.concat(messenger.encodeExecute(_execute("")), hex"00");
+
+        vm.expectRevert("invalid message length");
+        messenger.decodeExecute(encoded);
+    }
+
+    function test_DecodeMessage_RevertIfExecute() public {
+        bytes memory encoded = messenger.encodeExecute(_execute(""));
+
+        vm.expectRevert("invalid payloadId");
+        messenger.decodeMessage(encoded);
+    }
+
+    function test_SendExecute() public {
+        address sender = makeAddr("sender");
+        CrossChainMessengerStructs.ExecutePayload memory payload = _execute(hex"deadbeef");
+
+        for (uint64 nonce = 0; nonce < 2; ++nonce) {
+            payload.sender = bytes32(uint256(uint160(sender)));
+            payload.nonce = nonce;
+            vm.expectCall(
+                wormhole,
+                abi.encodeCall(
+                    IWormhole.publishMessage,
+                    (0, messenger.encodeExecute(payload), wormholeFinality)
+                )
+            );
+
+            vm.prank(sender);
+            uint64 sequence = messenger.sendExecute(
+                payload.computeUnits,
+                payload.programId,
+                payload.accounts,
+                payload.data
+            );
+            assertEq(sequence, 7);
+        }
+
+        assertEq(messenger.nextExecuteNonce(sender), 2);
+        assertEq(messenger.nextExecuteNonce(address(this)), 0);
+    }
+}
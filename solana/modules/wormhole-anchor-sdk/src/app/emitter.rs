@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::wormhole::SEED_PREFIX_EMITTER;
+
+/// Find the emitter PDA of `program_id`, i.e. the account that signs the
+/// program's Wormhole messages.
+pub fn find_emitter_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEED_PREFIX_EMITTER], program_id)
+}
+
+/// Signer seeds of the emitter PDA with the given `bump`.
+pub fn emitter_seeds(bump: &[u8; 1]) -> [&[u8]; 2] {
+    [SEED_PREFIX_EMITTER, bump]
+}
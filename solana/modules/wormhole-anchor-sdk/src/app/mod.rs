@@ -0,0 +1,23 @@
+//! Building blocks shared by programs that talk to their peers on other
+//! chains through Wormhole: foreign peer registry, emitter PDA handling and
+//! replay protection.
+
+pub use emitter::*;
+pub use peer::*;
+pub use replay::*;
+
+pub mod emitter;
+pub mod peer;
+pub mod replay;
+
+use anchor_lang::prelude::*;
+
+/// App-level config of a Wormhole-integrated program.
+pub trait WormholeApp {
+    /// Key allowed to manage the app (register peers, update config).
+    fn owner(&self) -> &Pubkey;
+
+    fn is_owner(&self, key: &Pubkey) -> bool {
+        self.owner() == key
+    }
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::wormhole::CHAIN_ID_SOLANA;
+
+/// Whether (`chain`, `address`) can identify a peer on another chain: the
+/// chain must be a non-zero foreign chain ID and the address non-zero.
+pub fn is_valid_peer(chain: u16, address: &[u8; 32]) -> bool {
+    chain > 0 && chain != CHAIN_ID_SOLANA && address.iter().any(|&x| x != 0)
+}
+
+/// Registered peer (emitter or contract) on another chain.
+pub trait WormholePeer {
+    fn chain(&self) -> u16;
+
+    fn address(&self) -> &[u8; 32];
+
+    /// Verify that a message from (`chain`, `address`) comes from this peer.
+    fn verify(&self, chain: u16, address: &[u8; 32]) -> bool {
+        chain == self.chain() && *address == *self.address()
+    }
+}
+
+/// Ready-made peer registry entry. Programs can embed it in their own
+/// accounts, usually seeded by the chain ID.
+#[derive(Debug, Default, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct ForeignPeer {
+    pub chain: u16,
+    pub address: [u8; 32],
+}
+
+impl ForeignPeer {
+    pub const LEN: usize = 2 + 32;
+
+    /// Create a peer, or `None` if (`chain`, `address`) is not a valid peer.
+    pub fn new(chain: u16, address: [u8; 32]) -> Option<Self> {
+        is_valid_peer(chain, &address).then_some(Self { chain, address })
+    }
+}
+
+impl WormholePeer for ForeignPeer {
+    fn chain(&self) -> u16 {
+        self.chain
+    }
+
+    fn address(&self) -> &[u8; 32] {
+        &self.address
+    }
+}
+
+#[test]
+fn peer_validation() {
+    let address = [1; 32];
+
+    assert!(is_valid_peer(2, &address));
+    assert!(!is_valid_peer(0, &address));
+    assert!(!is_valid_peer(CHAIN_ID_SOLANA, &address));
+    assert!(!is_valid_peer(2, &[0; 32]));
+}
+
+#[test]
+fn foreign_peer_validation() {
+    let address = [1; 32];
+
+    assert!(ForeignPeer::new(2, address).is_some());
+    assert!(ForeignPeer::new(CHAIN_ID_SOLANA, address).is_none());
+    assert!(ForeignPeer::new(2, [0; 32]).is_none());
+
+    let peer = ForeignPeer::new(2, address).unwrap();
+    assert!(peer.verify(2, &address));
+    assert!(!peer.verify(3, &address));
+    assert!(!peer.verify(2, &[2; 32]));
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+/// Size of one replay bitmap in bytes.
+pub const REPLAY_BITMAP_BYTES: usize = 1024;
+
+/// Number of sequences tracked by one replay bitmap.
+pub const REPLAY_BITMAP_BITS: u64 = REPLAY_BITMAP_BYTES as u64 * 8;
+
+/// Whether a per-message replay account (e.g. a received-message PDA or a
+/// Token Bridge claim) has already been created, i.e. the message consumed.
+pub fn is_consumed(account: &AccountInfo) -> bool {
+    !account.data_is_empty()
+}
+
+/// Index of the bitmap tracking `sequence`. Bitmaps are usually seeded by
+/// emitter chain and this index.
+pub fn replay_bitmap_index(sequence: u64) -> u64 {
+    sequence / REPLAY_BITMAP_BITS
+}
+
+/// Byte offset and bit mask of `sequence` within its bitmap.
+fn replay_bitmap_position(sequence: u64) -> (usize, u8) {
+    let bit = sequence % REPLAY_BITMAP_BITS;
+    ((bit / 8) as usize, 1 << (bit % 8))
+}
+
+/// Whether `sequence` is marked as consumed in `bitmap`.
+pub fn is_sequence_consumed(bitmap: &[u8; REPLAY_BITMAP_BYTES], sequence: u64) -> bool {
+    let (byte, mask) = replay_bitmap_position(sequence);
+    bitmap[byte] & mask != 0
+}
+
+/// Mark `sequence` as consumed in `bitmap`. Returns `false` if it already was.
+pub fn consume_sequence(bitmap: &mut [u8; REPLAY_BITMAP_BYTES], sequence: u64) -> bool {
+    let (byte, mask) = replay_bitmap_position(sequence);
+    if bitmap[byte] & mask != 0 {
+        return false;
+    }
+    bitmap[byte] |= mask;
+    true
+}
+
+#[test]
+fn replay_bitmap() {
+    let mut bitmap = [0; REPLAY_BITMAP_BYTES];
+
+    assert_eq!(replay_bitmap_index(0), 0);
+    assert_eq!(replay_bitmap_index(REPLAY_BITMAP_BITS - 1), 0);
+    assert_eq!(replay_bitmap_index(REPLAY_BITMAP_BITS), 1);
+
+    let sequence = 3 * REPLAY_BITMAP_BITS + 42;
+    assert!(!is_sequence_consumed(&bitmap, sequence));
+    assert!(consume_sequence(&mut bitmap, sequence));
+    assert!(is_sequence_consumed(&bitmap, sequence));
+    assert!(!consume_sequence(&mut bitmap, sequence));
+
+    // Neighbouring sequences are unaffected.
+    assert!(!is_sequence_consumed(&bitmap, sequence - 1));
+    assert!(!is_sequence_consumed(&bitmap, sequence + 1));
+    assert_eq!(bitmap.iter().map(|b| b.count_ones()).sum::<u32>(), 1);
+}
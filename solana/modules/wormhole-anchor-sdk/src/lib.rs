@@ -1,5 +1,6 @@
 #![allow(clippy::result_large_err)]
 
+pub mod app;
 pub mod wormhole;
 
 #[cfg(feature = "token-bridge")]
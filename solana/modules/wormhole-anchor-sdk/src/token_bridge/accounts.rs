@@ -3,7 +3,7 @@ use std::io::Write;
 use std::{io, ops::Deref};
 use wormhole_io::{Readable, Writeable, WriteableBytes};
 
-use crate::token_bridge::message::TransferHeader;
+use crate::token_bridge::message::{AssetMeta, TransferHeader};
 
 use super::program::TokenBridge;
 use crate::wormhole::{PostedVaa, CHAIN_ID_SOLANA};
@@ -258,11 +258,11 @@ impl<P: Readable> Readable for TransferWith<P> {
     }
 }
 
-impl<P: AnchorDeserialize + AnchorSerialize + Copy> TransferWith<P> {
+impl<P: AnchorDeserialize + AnchorSerialize + Clone> TransferWith<P> {
     pub fn new(meta: &TransferHeader, payload: &P) -> Self {
         Self {
             meta: *meta,
-            payload: *payload,
+            payload: payload.clone(),
         }
     }
 
@@ -339,6 +339,10 @@ pub type PostedTransferWithPayload = PostedVaa<TransferWithPayload>;
 /// generic payload type `P`.
 pub type PostedTransferWith<P> = PostedVaa<TransferWith<P>>;
 
+/// Posted VAA (verified Wormhole message) of a Token Bridge asset meta
+/// attestation.
+pub type PostedAssetMeta = PostedVaa<AssetMeta>;
+
 #[cfg(feature = "idl-build")]
 impl Discriminator for Config {
     const DISCRIMINATOR: &'static [u8] = &[];
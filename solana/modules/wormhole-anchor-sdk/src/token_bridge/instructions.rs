@@ -1,5 +1,4 @@
 use anchor_lang::{prelude::*, solana_program};
-use anchor_spl;
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
 /// Token Bridge instructions.
@@ -293,6 +292,200 @@ pub fn complete_transfer_wrapped_with_payload<'info>(
     .map_err(Into::into)
 }
 
+#[derive(Accounts)]
+pub struct TransferNative<'info> {
+    pub payer: AccountInfo<'info>,
+    pub config: AccountInfo<'info>,
+    pub from: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub custody: AccountInfo<'info>,
+    pub authority_signer: AccountInfo<'info>,
+    pub custody_signer: AccountInfo<'info>,
+    pub wormhole_bridge: AccountInfo<'info>,
+    pub wormhole_message: AccountInfo<'info>,
+    pub wormhole_emitter: AccountInfo<'info>,
+    pub wormhole_sequence: AccountInfo<'info>,
+    pub wormhole_fee_collector: AccountInfo<'info>,
+    pub clock: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub wormhole_program: AccountInfo<'info>,
+}
+
+impl<'info> TransferNative<'info> {
+    /// Accounts of the same transfer sent with a payload from `sender`.
+    pub fn with_payload(self, sender: AccountInfo<'info>) -> TransferNativeWithPayload<'info> {
+        TransferNativeWithPayload {
+            payer: self.payer,
+            config: self.config,
+            from: self.from,
+            mint: self.mint,
+            custody: self.custody,
+            authority_signer: self.authority_signer,
+            custody_signer: self.custody_signer,
+            wormhole_bridge: self.wormhole_bridge,
+            wormhole_message: self.wormhole_message,
+            wormhole_emitter: self.wormhole_emitter,
+            wormhole_sequence: self.wormhole_sequence,
+            wormhole_fee_collector: self.wormhole_fee_collector,
+            clock: self.clock,
+            sender,
+            rent: self.rent,
+            system_program: self.system_program,
+            token_program: self.token_program,
+            wormhole_program: self.wormhole_program,
+        }
+    }
+}
+
+pub fn transfer_native<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, TransferNative<'info>>,
+    batch_id: u32,
+    amount: u64,
+    fee: u64,
+    recipient_address: [u8; 32],
+    recipient_chain: u16,
+) -> Result<()> {
+    let ix = solana_program::instruction::Instruction {
+        program_id: ctx.program.key(),
+        accounts: vec![
+            AccountMeta::new(ctx.accounts.payer.key(), true),
+            AccountMeta::new_readonly(ctx.accounts.config.key(), false),
+            AccountMeta::new(ctx.accounts.from.key(), false),
+            AccountMeta::new(ctx.accounts.mint.key(), false),
+            AccountMeta::new(ctx.accounts.custody.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.authority_signer.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.custody_signer.key(), false),
+            AccountMeta::new(ctx.accounts.wormhole_bridge.key(), false),
+            AccountMeta::new(ctx.accounts.wormhole_message.key(), true),
+            AccountMeta::new_readonly(ctx.accounts.wormhole_emitter.key(), false),
+            AccountMeta::new(ctx.accounts.wormhole_sequence.key(), false),
+            AccountMeta::new(ctx.accounts.wormhole_fee_collector.key(), false),
+            AccountMeta::new_readonly(solana_program::sysvar::clock::id(), false),
+            AccountMeta::new_readonly(solana_program::sysvar::rent::id(), false),
+            AccountMeta::new_readonly(ctx.accounts.system_program.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.wormhole_program.key(), false),
+            AccountMeta::new_readonly(anchor_spl::token::spl_token::id(), false),
+        ],
+        data: Instruction::TransferNative {
+            batch_id,
+            amount,
+            fee,
+            recipient_address,
+            recipient_chain,
+        }
+        .try_to_vec()?,
+    };
+
+    solana_program::program::invoke_signed(
+        &ix,
+        &ToAccountInfos::to_account_infos(&ctx),
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+#[derive(Accounts)]
+pub struct CompleteTransferNative<'info> {
+    pub payer: AccountInfo<'info>,
+    pub config: AccountInfo<'info>,
+    pub vaa: AccountInfo<'info>,
+    pub claim: AccountInfo<'info>,
+    pub foreign_endpoint: AccountInfo<'info>,
+    pub to: AccountInfo<'info>,
+    pub to_fees: AccountInfo<'info>,
+    pub custody: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub custody_signer: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub wormhole_program: AccountInfo<'info>,
+}
+
+pub fn complete_transfer_native<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, CompleteTransferNative<'info>>,
+) -> Result<()> {
+    let ix = solana_program::instruction::Instruction {
+        program_id: ctx.program.key(),
+        accounts: vec![
+            AccountMeta::new(ctx.accounts.payer.key(), true),
+            AccountMeta::new_readonly(ctx.accounts.config.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.vaa.key(), false),
+            AccountMeta::new(ctx.accounts.claim.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.foreign_endpoint.key(), false),
+            AccountMeta::new(ctx.accounts.to.key(), false),
+            AccountMeta::new(ctx.accounts.to_fees.key(), false),
+            AccountMeta::new(ctx.accounts.custody.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.mint.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.custody_signer.key(), false),
+            AccountMeta::new_readonly(solana_program::sysvar::rent::id(), false),
+            AccountMeta::new_readonly(ctx.accounts.system_program.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.wormhole_program.key(), false),
+            AccountMeta::new_readonly(anchor_spl::token::spl_token::id(), false),
+        ],
+        data: Instruction::CompleteNative {}.try_to_vec()?,
+    };
+
+    solana_program::program::invoke_signed(
+        &ix,
+        &ToAccountInfos::to_account_infos(&ctx),
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+#[derive(Accounts)]
+pub struct CompleteTransferWrapped<'info> {
+    pub payer: AccountInfo<'info>,
+    pub config: AccountInfo<'info>,
+    pub vaa: AccountInfo<'info>,
+    pub claim: AccountInfo<'info>,
+    pub foreign_endpoint: AccountInfo<'info>,
+    pub to: AccountInfo<'info>,
+    pub to_fees: AccountInfo<'info>,
+    pub wrapped_mint: AccountInfo<'info>,
+    pub wrapped_metadata: AccountInfo<'info>,
+    pub mint_authority: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub wormhole_program: AccountInfo<'info>,
+}
+
+pub fn complete_transfer_wrapped<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, CompleteTransferWrapped<'info>>,
+) -> Result<()> {
+    let ix = solana_program::instruction::Instruction {
+        program_id: ctx.program.key(),
+        accounts: vec![
+            AccountMeta::new(ctx.accounts.payer.key(), true),
+            AccountMeta::new_readonly(ctx.accounts.config.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.vaa.key(), false),
+            AccountMeta::new(ctx.accounts.claim.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.foreign_endpoint.key(), false),
+            AccountMeta::new(ctx.accounts.to.key(), false),
+            AccountMeta::new(ctx.accounts.to_fees.key(), false),
+            AccountMeta::new(ctx.accounts.wrapped_mint.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.wrapped_metadata.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.mint_authority.key(), false),
+            AccountMeta::new_readonly(solana_program::sysvar::rent::id(), false),
+            AccountMeta::new_readonly(ctx.accounts.system_program.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.wormhole_program.key(), false),
+            AccountMeta::new_readonly(anchor_spl::token::spl_token::id(), false),
+        ],
+        data: Instruction::CompleteWrapped {}.try_to_vec()?,
+    };
+
+    solana_program::program::invoke_signed(
+        &ix,
+        &ToAccountInfos::to_account_infos(&ctx),
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
 #[derive(Accounts)]
 pub struct TransferWrapped<'info> {
     pub payer: AccountInfo<'info>,
@@ -314,6 +507,32 @@ pub struct TransferWrapped<'info> {
     pub wormhole_program: AccountInfo<'info>,
 }
 
+impl<'info> TransferWrapped<'info> {
+    /// Accounts of the same transfer sent with a payload from `sender`.
+    pub fn with_payload(self, sender: AccountInfo<'info>) -> TransferWrappedWithPayload<'info> {
+        TransferWrappedWithPayload {
+            payer: self.payer,
+            config: self.config,
+            from: self.from,
+            from_owner: self.from_owner,
+            wrapped_mint: self.wrapped_mint,
+            wrapped_metadata: self.wrapped_metadata,
+            authority_signer: self.authority_signer,
+            wormhole_bridge: self.wormhole_bridge,
+            wormhole_message: self.wormhole_message,
+            wormhole_emitter: self.wormhole_emitter,
+            wormhole_sequence: self.wormhole_sequence,
+            wormhole_fee_collector: self.wormhole_fee_collector,
+            clock: self.clock,
+            sender,
+            rent: self.rent,
+            system_program: self.system_program,
+            token_program: self.token_program,
+            wormhole_program: self.wormhole_program,
+        }
+    }
+}
+
 pub fn transfer_wrapped<'info>(
     ctx: CpiContext<'_, '_, '_, 'info, TransferWrapped<'info>>,
     batch_id: u32,
@@ -360,3 +579,104 @@ pub fn transfer_wrapped<'info>(
     )
     .map_err(Into::into)
 }
+
+#[derive(Accounts)]
+pub struct AttestToken<'info> {
+    pub payer: AccountInfo<'info>,
+    pub config: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub wrapped_metadata: AccountInfo<'info>,
+    pub spl_metadata: AccountInfo<'info>,
+    pub wormhole_bridge: AccountInfo<'info>,
+    pub wormhole_message: AccountInfo<'info>,
+    pub wormhole_emitter: AccountInfo<'info>,
+    pub wormhole_sequence: AccountInfo<'info>,
+    pub wormhole_fee_collector: AccountInfo<'info>,
+    pub clock: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub wormhole_program: AccountInfo<'info>,
+}
+
+pub fn attest_token<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, AttestToken<'info>>,
+    batch_id: u32,
+) -> Result<()> {
+    let ix = solana_program::instruction::Instruction {
+        program_id: ctx.program.key(),
+        accounts: vec![
+            AccountMeta::new(ctx.accounts.payer.key(), true),
+            AccountMeta::new(ctx.accounts.config.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.mint.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.wrapped_metadata.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.spl_metadata.key(), false),
+            AccountMeta::new(ctx.accounts.wormhole_bridge.key(), false),
+            AccountMeta::new(ctx.accounts.wormhole_message.key(), true),
+            AccountMeta::new_readonly(ctx.accounts.wormhole_emitter.key(), false),
+            AccountMeta::new(ctx.accounts.wormhole_sequence.key(), false),
+            AccountMeta::new(ctx.accounts.wormhole_fee_collector.key(), false),
+            AccountMeta::new_readonly(solana_program::sysvar::clock::id(), false),
+            AccountMeta::new_readonly(solana_program::sysvar::rent::id(), false),
+            AccountMeta::new_readonly(ctx.accounts.system_program.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.wormhole_program.key(), false),
+        ],
+        data: Instruction::AttestToken { batch_id }.try_to_vec()?,
+    };
+
+    solana_program::program::invoke_signed(
+        &ix,
+        &ToAccountInfos::to_account_infos(&ctx),
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+#[derive(Accounts)]
+pub struct CreateWrapped<'info> {
+    pub payer: AccountInfo<'info>,
+    pub config: AccountInfo<'info>,
+    pub foreign_endpoint: AccountInfo<'info>,
+    pub vaa: AccountInfo<'info>,
+    pub claim: AccountInfo<'info>,
+    pub wrapped_mint: AccountInfo<'info>,
+    pub wrapped_metadata: AccountInfo<'info>,
+    pub spl_metadata: AccountInfo<'info>,
+    pub mint_authority: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub spl_metadata_program: AccountInfo<'info>,
+    pub wormhole_program: AccountInfo<'info>,
+}
+
+pub fn create_wrapped<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, CreateWrapped<'info>>,
+) -> Result<()> {
+    let ix = solana_program::instruction::Instruction {
+        program_id: ctx.program.key(),
+        accounts: vec![
+            AccountMeta::new(ctx.accounts.payer.key(), true),
+            AccountMeta::new_readonly(ctx.accounts.config.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.foreign_endpoint.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.vaa.key(), false),
+            AccountMeta::new(ctx.accounts.claim.key(), false),
+            AccountMeta::new(ctx.accounts.wrapped_mint.key(), false),
+            AccountMeta::new(ctx.accounts.wrapped_metadata.key(), false),
+            AccountMeta::new(ctx.accounts.spl_metadata.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.mint_authority.key(), false),
+            AccountMeta::new_readonly(solana_program::sysvar::rent::id(), false),
+            AccountMeta::new_readonly(ctx.accounts.system_program.key(), false),
+            AccountMeta::new_readonly(anchor_spl::token::spl_token::id(), false),
+            AccountMeta::new_readonly(ctx.accounts.spl_metadata_program.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.wormhole_program.key(), false),
+        ],
+        data: Instruction::CreateWrapped {}.try_to_vec()?,
+    };
+
+    solana_program::program::invoke_signed(
+        &ix,
+        &ToAccountInfos::to_account_infos(&ctx),
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
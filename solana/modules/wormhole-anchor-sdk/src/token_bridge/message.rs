@@ -115,6 +115,101 @@ impl AnchorDeserialize for TransferHeader {
     }
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+/// Token Bridge attestation of a token's metadata, needed on a foreign chain
+/// before its wrapped asset can be created.
+pub struct AssetMeta {
+    /// Address of the token. Left-zero-padded if shorter than 32 bytes
+    pub token_address: [u8; 32],
+    /// Chain ID of the token
+    pub token_chain: u16,
+    /// Number of decimals of the token
+    pub decimals: u8,
+    /// Symbol of the token (UTF-8, right-zero-padded)
+    pub symbol: [u8; 32],
+    /// Name of the token (UTF-8, right-zero-padded)
+    pub name: [u8; 32],
+}
+
+impl AssetMeta {
+    #[cfg(test)]
+    pub fn random(rng: &mut impl rand::Rng) -> Self {
+        AssetMeta {
+            token_address: rng.gen(),
+            token_chain: rng.gen(),
+            decimals: rng.gen(),
+            symbol: rng.gen(),
+            name: rng.gen(),
+        }
+    }
+}
+
+impl Writeable for AssetMeta {
+    fn write<W>(&self, writer: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let AssetMeta {
+            token_address,
+            token_chain,
+            decimals,
+            symbol,
+            name,
+        } = self;
+
+        PAYLOAD_ID_ASSET_META.write(writer)?;
+        token_address.write(writer)?;
+        token_chain.write(writer)?;
+        decimals.write(writer)?;
+        symbol.write(writer)?;
+        name.write(writer)?;
+
+        Ok(())
+    }
+
+    fn written_size(&self) -> usize {
+        Self::SIZE.unwrap()
+    }
+}
+
+impl Readable for AssetMeta {
+    const SIZE: Option<usize> = Some(1 + 32 + 2 + 1 + 32 + 32);
+
+    fn read<R>(reader: &mut R) -> io::Result<Self>
+    where
+        Self: Sized,
+        R: io::Read,
+    {
+        // Verify Payload ID is an asset meta attestation.
+        if u8::read(reader)? != PAYLOAD_ID_ASSET_META {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Invalid Token Bridge Asset Meta",
+            ));
+        }
+
+        Ok(AssetMeta {
+            token_address: Readable::read(reader)?,
+            token_chain: Readable::read(reader)?,
+            decimals: Readable::read(reader)?,
+            symbol: Readable::read(reader)?,
+            name: Readable::read(reader)?,
+        })
+    }
+}
+
+impl AnchorSerialize for AssetMeta {
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.write(writer)
+    }
+}
+
+impl AnchorDeserialize for AssetMeta {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        Readable::read(reader)
+    }
+}
+
 /// Bespoke U256 type which as actually a u64 to abstract the padding in (de)serialization.
 #[derive(Debug, PartialEq)]
 struct U256(u64);
@@ -211,3 +306,18 @@ fn transfer_header_roundtrip_serialization() {
 
     assert_eq!(original, deserialized);
 }
+
+#[test]
+fn asset_meta_roundtrip_serialization() {
+    let original = AssetMeta::random(&mut rand::thread_rng());
+
+    let deserialized = AssetMeta::deserialize(
+        &mut original
+            .try_to_vec()
+            .expect("Serialization should work")
+            .as_ref(),
+    )
+    .expect("Deserialization should work");
+
+    assert_eq!(original, deserialized);
+}
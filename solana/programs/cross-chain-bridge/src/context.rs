@@ -4,6 +4,7 @@ use anchor_spl::{
     token::{Mint, Token, TokenAccount},
 };
 use wormhole_anchor_sdk::{
+    app::{WormholeApp, WormholePeer},
     token_bridge::{self, program::TokenBridge},
     wormhole::{self, program::Wormhole},
 };
@@ -11,11 +12,15 @@ use wormhole_anchor_sdk::{
 use crate::{
     error::BridgeError,
     message::PostedTokenMessage,
-    state::{ForeignContract, RedeemerConfig, SenderConfig},
+    state::{
+        ChainRelayerFee, ForeignContract, GovernorConfig, OutboundChainLimit, PendingRelease,
+        RedeemReceipt, RedeemerConfig, RefundConfig, RegisteredToken, SenderConfig,
+        TokenRelayerFee, TransferReceipt,
+    },
 };
 
-pub const SEED_PREFIX_BRIDGED: &[u8; 7] = b"bridged";
 pub const SEED_PREFIX_TMP: &[u8; 3] = b"tmp";
+pub const SEED_PREFIX_ESCROW: &[u8; 6] = b"escrow";
 
 #[derive(Accounts)]
 pub struct Initialize<'info> {
@@ -118,7 +123,7 @@ pub struct RegisterForeignContract<'info> {
     pub owner: Signer<'info>,
 
     #[account(
-        has_one = owner @ BridgeError::OwnerOnly,
+        constraint = config.is_owner(&owner.key()) @ BridgeError::OwnerOnly,
         seeds = [SenderConfig::SEED_PREFIX],
         bump
     )]
@@ -151,6 +156,236 @@ pub struct RegisterForeignContract<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Context for registering a token for bridging.
+#[derive(Accounts)]
+pub struct RegisterToken<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ BridgeError::OwnerOnly,
+        seeds = [SenderConfig::SEED_PREFIX],
+        bump
+    )]
+    pub config: Box<Account<'info, SenderConfig>>,
+
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [
+            RegisteredToken::SEED_PREFIX,
+            mint.key().as_ref()
+        ],
+        bump,
+        space = RegisteredToken::MAXIMUM_SIZE
+    )]
+    pub registered_token: Box<Account<'info, RegisteredToken>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for updating the transfer limits of a registered token.
+#[derive(Accounts)]
+pub struct SetTokenLimits<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ BridgeError::OwnerOnly,
+        seeds = [SenderConfig::SEED_PREFIX],
+        bump
+    )]
+    pub config: Box<Account<'info, SenderConfig>>,
+
+    #[account(
+        mut,
+        seeds = [
+            RegisteredToken::SEED_PREFIX,
+            registered_token.mint.as_ref()
+        ],
+        bump,
+    )]
+    pub registered_token: Box<Account<'info, RegisteredToken>>,
+}
+
+/// Context for setting the inbound threshold and cap of a registered token.
+#[derive(Accounts)]
+pub struct SetInboundLimits<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ BridgeError::OwnerOnly,
+        seeds = [RedeemerConfig::SEED_PREFIX],
+        bump
+    )]
+    pub config: Box<Account<'info, RedeemerConfig>>,
+
+    #[account(
+        mut,
+        seeds = [
+            RegisteredToken::SEED_PREFIX,
+            registered_token.mint.as_ref()
+        ],
+        bump,
+    )]
+    pub registered_token: Box<Account<'info, RegisteredToken>>,
+}
+
+/// Context for setting the SOL exchange rate and gas drop-off bounds of a
+/// registered token.
+#[derive(Accounts)]
+pub struct SetSolExchangeRate<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ BridgeError::OwnerOnly,
+        seeds = [RedeemerConfig::SEED_PREFIX],
+        bump
+    )]
+    pub config: Box<Account<'info, RedeemerConfig>>,
+
+    #[account(
+        mut,
+        seeds = [
+            RegisteredToken::SEED_PREFIX,
+            registered_token.mint.as_ref()
+        ],
+        bump,
+    )]
+    pub registered_token: Box<Account<'info, RegisteredToken>>,
+}
+
+/// Context for setting the outbound cap of a registered token towards a chain.
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct SetOutboundChainLimit<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ BridgeError::OwnerOnly,
+        seeds = [SenderConfig::SEED_PREFIX],
+        bump
+    )]
+    pub config: Box<Account<'info, SenderConfig>>,
+
+    #[account(
+        seeds = [
+            RegisteredToken::SEED_PREFIX,
+            registered_token.mint.as_ref()
+        ],
+        bump,
+    )]
+    pub registered_token: Box<Account<'info, RegisteredToken>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [
+            OutboundChainLimit::SEED_PREFIX,
+            registered_token.mint.as_ref(),
+            &chain.to_le_bytes()[..]
+        ],
+        bump,
+        space = OutboundChainLimit::MAXIMUM_SIZE
+    )]
+    pub outbound_chain_limit: Box<Account<'info, OutboundChainLimit>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for querying the outbound capacity of a registered token.
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct GetOutboundCapacity<'info> {
+    #[account(
+        seeds = [
+            RegisteredToken::SEED_PREFIX,
+            registered_token.mint.as_ref()
+        ],
+        bump,
+    )]
+    pub registered_token: Box<Account<'info, RegisteredToken>>,
+
+    #[account(
+        seeds = [
+            OutboundChainLimit::SEED_PREFIX,
+            registered_token.mint.as_ref(),
+            &chain.to_le_bytes()[..]
+        ],
+        bump,
+    )]
+    /// CHECK: Outbound cap towards `chain`. Empty if there is none.
+    pub outbound_chain_limit: UncheckedAccount<'info>,
+}
+
+/// Context for setting the relayer fee override of a source chain.
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct SetChainRelayerFee<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ BridgeError::OwnerOnly,
+        seeds = [RedeemerConfig::SEED_PREFIX],
+        bump
+    )]
+    pub config: Box<Account<'info, RedeemerConfig>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [
+            ChainRelayerFee::SEED_PREFIX,
+            &chain.to_le_bytes()[..]
+        ],
+        bump,
+        space = ChainRelayerFee::MAXIMUM_SIZE
+    )]
+    pub chain_relayer_fee: Box<Account<'info, ChainRelayerFee>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for setting the relayer fee override of a registered token.
+#[derive(Accounts)]
+pub struct SetTokenRelayerFee<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ BridgeError::OwnerOnly,
+        seeds = [RedeemerConfig::SEED_PREFIX],
+        bump
+    )]
+    pub config: Box<Account<'info, RedeemerConfig>>,
+
+    #[account(
+        seeds = [
+            RegisteredToken::SEED_PREFIX,
+            registered_token.mint.as_ref()
+        ],
+        bump,
+    )]
+    pub registered_token: Box<Account<'info, RegisteredToken>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [
+            TokenRelayerFee::SEED_PREFIX,
+            registered_token.mint.as_ref()
+        ],
+        bump,
+        space = TokenRelayerFee::MAXIMUM_SIZE
+    )]
+    pub token_relayer_fee: Box<Account<'info, TokenRelayerFee>>,
+
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 pub struct UpdateRelayerFee<'info> {
     /// CHECK: Owner of the program.
@@ -158,7 +393,7 @@ pub struct UpdateRelayerFee<'info> {
 
     #[account(
         mut,
-        has_one = owner @ BridgeError::OwnerOnly,
+        constraint = config.is_owner(&owner.key()) @ BridgeError::OwnerOnly,
         seeds = [RedeemerConfig::SEED_PREFIX],
         bump
     )]
@@ -197,10 +432,34 @@ pub struct SendNativeTokensWithPayload<'info> {
     #[account(mut)]
     pub mint: Box<Account<'info, Mint>>,
 
+    #[account(
+        mut,
+        seeds = [
+            RegisteredToken::SEED_PREFIX,
+            mint.key().as_ref()
+        ],
+        bump,
+        constraint = !registered_token.data_is_empty() @ BridgeError::TokenNotRegistered
+    )]
+    /// CHECK: Token registration, deserialized by the instruction handler.
+    pub registered_token: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            OutboundChainLimit::SEED_PREFIX,
+            mint.key().as_ref(),
+            &recipient_chain.to_le_bytes()[..]
+        ],
+        bump,
+    )]
+    /// CHECK: Outbound cap towards `recipient_chain`. Empty if there is none.
+    pub outbound_chain_limit: UncheckedAccount<'info>,
+
     #[account(
         mut,
         associated_token::mint = mint,
-        associated_token::authority = payer,
+        associated_token::authority = payer
     )]
     pub from_token_account: Box<Account<'info, TokenAccount>>,
 
@@ -210,10 +469,11 @@ pub struct SendNativeTokensWithPayload<'info> {
         seeds = [
             SEED_PREFIX_TMP,
             mint.key().as_ref(),
+            payer.key().as_ref(),
         ],
         bump,
         token::mint = mint,
-        token::authority = config,
+        token::authority = config
     )]
     pub tmp_token_account: Box<Account<'info, TokenAccount>>,
 
@@ -253,16 +513,22 @@ pub struct SendNativeTokensWithPayload<'info> {
     )]
     pub wormhole_bridge: Box<Account<'info, wormhole::BridgeData>>,
 
+    #[account(mut)]
+    /// Wormhole message account, a fresh keypair.
+    pub wormhole_message: Signer<'info>,
+
     #[account(
-        mut,
+        init,
+        payer = payer,
         seeds = [
-            SEED_PREFIX_BRIDGED,
-            &token_bridge_sequence.next_value().to_le_bytes()[..]
+            TransferReceipt::SEED_PREFIX,
+            wormhole_message.key().as_ref()
         ],
         bump,
+        space = TransferReceipt::MAXIMUM_SIZE
     )]
-    /// CHECK: Wormhole message account.
-    pub wormhole_message: UncheckedAccount<'info>,
+    /// Record of this transfer. The sender can close it to reclaim rent.
+    pub transfer_receipt: Box<Account<'info, TransferReceipt>>,
 
     #[account(
         mut,
@@ -290,50 +556,62 @@ pub struct SendNativeTokensWithPayload<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
+/// Context for wrapping lamports into SOL and sending them with payload.
 #[derive(Accounts)]
-#[instruction(vaa_hash: [u8; 32])]
-pub struct RedeemNativeTransferWithPayload<'info> {
+#[instruction(
+    batch_id: u32,
+    amount: u64,
+    recipient_address: [u8; 32],
+    recipient_chain: u16,
+)]
+pub struct SendSolWithPayload<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
     #[account(
-        mut,
-        constraint = payer.key() == recipient.key() || payer_token_account.key() == anchor_spl::associated_token::get_associated_token_address(&payer.key(), &mint.key()) @ BridgeError::InvalidPayerAta
-    )]
-    /// CHECK: Payer's token account.
-    pub payer_token_account: UncheckedAccount<'info>,
-
-    #[account(
-        seeds = [RedeemerConfig::SEED_PREFIX],
+        seeds = [SenderConfig::SEED_PREFIX],
         bump
     )]
-    pub config: Box<Account<'info, RedeemerConfig>>,
+    pub config: Box<Account<'info, SenderConfig>>,
 
     #[account(
         seeds = [
             ForeignContract::SEED_PREFIX,
-            &vaa.emitter_chain().to_le_bytes()[..]
+            &recipient_chain.to_le_bytes()[..]
         ],
         bump,
-        constraint = foreign_contract.verify(&vaa) @ BridgeError::InvalidForeignContract
     )]
     pub foreign_contract: Box<Account<'info, ForeignContract>>,
 
     #[account(
-        constraint = mint.key() == vaa.data().mint()
+        mut,
+        address = anchor_spl::token::spl_token::native_mint::ID @ BridgeError::NotNativeMint
     )]
     pub mint: Box<Account<'info, Mint>>,
 
     #[account(
         mut,
-        associated_token::mint = mint,
-        associated_token::authority = recipient
+        seeds = [
+            RegisteredToken::SEED_PREFIX,
+            mint.key().as_ref()
+        ],
+        bump,
+        constraint = !registered_token.data_is_empty() @ BridgeError::TokenNotRegistered
     )]
-    pub recipient_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: Token registration, deserialized by the instruction handler.
+    pub registered_token: UncheckedAccount<'info>,
 
-    #[account(mut)]
-    /// CHECK: Recipient may differ from payer.
-    pub recipient: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [
+            OutboundChainLimit::SEED_PREFIX,
+            mint.key().as_ref(),
+            &recipient_chain.to_le_bytes()[..]
+        ],
+        bump,
+    )]
+    /// CHECK: Outbound cap towards `recipient_chain`. Empty if there is none.
+    pub outbound_chain_limit: UncheckedAccount<'info>,
 
     #[account(
         init,
@@ -341,14 +619,16 @@ pub struct RedeemNativeTransferWithPayload<'info> {
         seeds = [
             SEED_PREFIX_TMP,
             mint.key().as_ref(),
+            payer.key().as_ref(),
         ],
         bump,
         token::mint = mint,
-        token::authority = config
+        token::authority = config,
     )]
     pub tmp_token_account: Box<Account<'info, TokenAccount>>,
 
     pub wormhole_program: Program<'info, Wormhole>,
+
     pub token_bridge_program: Program<'info, TokenBridge>,
 
     #[account(
@@ -357,29 +637,257 @@ pub struct RedeemNativeTransferWithPayload<'info> {
     pub token_bridge_config: Box<Account<'info, token_bridge::Config>>,
 
     #[account(
-        seeds = [
-            wormhole::SEED_PREFIX_POSTED_VAA,
-            &vaa_hash
-        ],
+        mut,
+        seeds = [mint.key().as_ref()],
         bump,
-        seeds::program = wormhole_program.key,
-        constraint = vaa.data().to() == crate::ID || vaa.data().to() == config.key() @ BridgeError::InvalidTransferToAddress,
-        constraint = vaa.data().to_chain() == wormhole::CHAIN_ID_SOLANA @ BridgeError::InvalidTransferToChain,
-        constraint = vaa.data().token_chain() == wormhole::CHAIN_ID_SOLANA @ BridgeError::InvalidTransferTokenChain
+        seeds::program = token_bridge_program.key
     )]
-    pub vaa: Box<Account<'info, PostedTokenMessage>>,
-
-    #[account(mut)]
-    /// CHECK: Token Bridge claim account.
-    pub token_bridge_claim: UncheckedAccount<'info>,
+    /// CHECK: Token Bridge custody account.
+    pub token_bridge_custody: UncheckedAccount<'info>,
 
     #[account(
-        address = foreign_contract.token_bridge_foreign_endpoint @ BridgeError::InvalidTokenBridgeForeignEndpoint
+        address = config.token_bridge.authority_signer @ BridgeError::InvalidTokenBridgeAuthoritySigner
     )]
-    pub token_bridge_foreign_endpoint: Box<Account<'info, token_bridge::EndpointRegistration>>,
+    /// CHECK: Token Bridge authority signer.
+    pub token_bridge_authority_signer: UncheckedAccount<'info>,
 
     #[account(
-        mut,
+        address = config.token_bridge.custody_signer @ BridgeError::InvalidTokenBridgeCustodySigner
+    )]
+    /// CHECK: Token Bridge custody signer.
+    pub token_bridge_custody_signer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = config.token_bridge.wormhole_bridge @ BridgeError::InvalidWormholeBridge,
+    )]
+    pub wormhole_bridge: Box<Account<'info, wormhole::BridgeData>>,
+
+    #[account(mut)]
+    /// Wormhole message account, a fresh keypair.
+    pub wormhole_message: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            TransferReceipt::SEED_PREFIX,
+            wormhole_message.key().as_ref()
+        ],
+        bump,
+        space = TransferReceipt::MAXIMUM_SIZE
+    )]
+    /// Record of this transfer. The sender can close it to reclaim rent.
+    pub transfer_receipt: Box<Account<'info, TransferReceipt>>,
+
+    #[account(
+        mut,
+        address = config.token_bridge.emitter @ BridgeError::InvalidTokenBridgeEmitter
+    )]
+    /// CHECK: Token Bridge emitter.
+    pub token_bridge_emitter: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = config.token_bridge.sequence @ BridgeError::InvalidTokenBridgeSequence
+    )]
+    pub token_bridge_sequence: Box<Account<'info, wormhole::SequenceTracker>>,
+
+    #[account(
+        mut,
+        address = config.token_bridge.wormhole_fee_collector @ BridgeError::InvalidWormholeFeeCollector
+    )]
+    pub wormhole_fee_collector: Box<Account<'info, wormhole::FeeCollector>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32])]
+pub struct RedeemNativeTransferWithPayload<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = payer.key() == recipient.key() || payer_token_account.key() == anchor_spl::associated_token::get_associated_token_address_with_program_id(&payer.key(), &mint.key(), &token_program.key()) @ BridgeError::InvalidPayerAta
+    )]
+    /// CHECK: Payer's ATA, created by the handler if it does not exist.
+    pub payer_token_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [RedeemerConfig::SEED_PREFIX],
+        bump
+    )]
+    /// Writable so unwrapped SOL can pass through it.
+    pub config: Box<Account<'info, RedeemerConfig>>,
+
+    #[account(
+        seeds = [
+            ForeignContract::SEED_PREFIX,
+            &vaa.emitter_chain().to_le_bytes()[..]
+        ],
+        bump,
+        constraint = foreign_contract.verify(vaa.emitter_chain(), vaa.data().from_address()) @ BridgeError::InvalidForeignContract
+    )]
+    pub foreign_contract: Box<Account<'info, ForeignContract>>,
+
+    #[account(
+        constraint = mint.key() == vaa.data().mint()
+    )]
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [
+            RegisteredToken::SEED_PREFIX,
+            mint.key().as_ref()
+        ],
+        bump,
+        constraint = !registered_token.data_is_empty() @ BridgeError::TokenNotRegistered
+    )]
+    /// CHECK: Token registration, deserialized by the instruction handler.
+    pub registered_token: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            ChainRelayerFee::SEED_PREFIX,
+            &vaa.emitter_chain().to_le_bytes()[..]
+        ],
+        bump,
+    )]
+    /// CHECK: Relayer fee override of the source chain. Empty if there is none.
+    pub chain_relayer_fee: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            TokenRelayerFee::SEED_PREFIX,
+            mint.key().as_ref()
+        ],
+        bump,
+    )]
+    /// CHECK: Relayer fee override of the token. Empty if there is none.
+    pub token_relayer_fee: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Destination of the payload's recipient kind, checked by the
+    /// handler. A missing recipient ATA is created by the handler.
+    pub recipient_token_account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Recipient may differ from payer. For a token account recipient
+    /// kind, this is the account's owner.
+    pub recipient: UncheckedAccount<'info>,
+
+    /// CHECK: Program named by a call recipient kind, checked by the handler.
+    /// Required for that kind only.
+    pub recipient_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Transfer call PDA that signs the call into a call recipient
+    /// program, checked by the handler. Required for that kind only.
+    pub transfer_call_signer: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            SEED_PREFIX_TMP,
+            mint.key().as_ref(),
+            &vaa_hash[..],
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = config
+    )]
+    pub tmp_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [GovernorConfig::SEED_PREFIX],
+        bump,
+    )]
+    /// Required if the transfer is delayed.
+    pub governor_config: Option<Box<Account<'info, GovernorConfig>>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            PendingRelease::SEED_PREFIX,
+            &vaa_hash[..]
+        ],
+        bump,
+        space = PendingRelease::MAXIMUM_SIZE
+    )]
+    /// Required if, and only if, the transfer is delayed.
+    pub pending_release: Option<Box<Account<'info, PendingRelease>>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            SEED_PREFIX_ESCROW,
+            &vaa_hash[..]
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = config
+    )]
+    /// Holds the recipient's tokens of a delayed transfer.
+    pub escrow_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            RedeemReceipt::SEED_PREFIX,
+            &vaa_hash[..]
+        ],
+        bump,
+        space = RedeemReceipt::MAXIMUM_SIZE
+    )]
+    /// Record of the redeemed transfer.
+    pub receipt: Box<Account<'info, RedeemReceipt>>,
+
+    #[account(mut)]
+    /// Receipt of the outbound transfer a refund sends back, marked refunded.
+    pub refunded_transfer_receipt: Option<Box<Account<'info, TransferReceipt>>>,
+
+    pub wormhole_program: Program<'info, Wormhole>,
+    pub token_bridge_program: Program<'info, TokenBridge>,
+
+    #[account(
+        address = config.token_bridge.config @ BridgeError::InvalidTokenBridgeConfig
+    )]
+    pub token_bridge_config: Box<Account<'info, token_bridge::Config>>,
+
+    #[account(
+        seeds = [
+            wormhole::SEED_PREFIX_POSTED_VAA,
+            &vaa_hash
+        ],
+        bump,
+        seeds::program = wormhole_program.key,
+        constraint = vaa.data().to() == crate::ID || vaa.data().to() == config.key() @ BridgeError::InvalidTransferToAddress,
+        constraint = vaa.data().to_chain() == wormhole::CHAIN_ID_SOLANA @ BridgeError::InvalidTransferToChain,
+        constraint = vaa.data().token_chain() == wormhole::CHAIN_ID_SOLANA @ BridgeError::InvalidTransferTokenChain
+    )]
+    pub vaa: Box<Account<'info, PostedTokenMessage>>,
+
+    #[account(mut)]
+    /// CHECK: Token Bridge claim account.
+    pub token_bridge_claim: UncheckedAccount<'info>,
+
+    #[account(
+        address = foreign_contract.token_bridge_foreign_endpoint @ BridgeError::InvalidTokenBridgeForeignEndpoint
+    )]
+    pub token_bridge_foreign_endpoint: Box<Account<'info, token_bridge::EndpointRegistration>>,
+
+    #[account(
+        mut,
         seeds = [mint.key().as_ref()],
         bump,
         seeds::program = token_bridge_program.key
@@ -437,6 +945,30 @@ pub struct SendWrappedTokensWithPayload<'info> {
     )]
     pub token_bridge_wrapped_mint: Box<Account<'info, token_bridge::WrappedMint>>,
 
+    #[account(
+        mut,
+        seeds = [
+            RegisteredToken::SEED_PREFIX,
+            token_bridge_wrapped_mint.key().as_ref()
+        ],
+        bump,
+        constraint = !registered_token.data_is_empty() @ BridgeError::TokenNotRegistered
+    )]
+    /// CHECK: Token registration, deserialized by the instruction handler.
+    pub registered_token: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            OutboundChainLimit::SEED_PREFIX,
+            token_bridge_wrapped_mint.key().as_ref(),
+            &recipient_chain.to_le_bytes()[..]
+        ],
+        bump,
+    )]
+    /// CHECK: Outbound cap towards `recipient_chain`. Empty if there is none.
+    pub outbound_chain_limit: UncheckedAccount<'info>,
+
     #[account(
         mut,
         associated_token::mint = token_bridge_wrapped_mint,
@@ -450,6 +982,7 @@ pub struct SendWrappedTokensWithPayload<'info> {
         seeds = [
             SEED_PREFIX_TMP,
             token_bridge_wrapped_mint.key().as_ref(),
+            payer.key().as_ref(),
         ],
         bump,
         token::mint = token_bridge_wrapped_mint,
@@ -488,16 +1021,22 @@ pub struct SendWrappedTokensWithPayload<'info> {
     )]
     pub wormhole_bridge: Box<Account<'info, wormhole::BridgeData>>,
 
+    #[account(mut)]
+    /// Wormhole message account, a fresh keypair.
+    pub wormhole_message: Signer<'info>,
+
     #[account(
-        mut,
+        init,
+        payer = payer,
         seeds = [
-            SEED_PREFIX_BRIDGED,
-            &token_bridge_sequence.next_value().to_le_bytes()[..]
+            TransferReceipt::SEED_PREFIX,
+            wormhole_message.key().as_ref()
         ],
         bump,
+        space = TransferReceipt::MAXIMUM_SIZE
     )]
-    /// CHECK: Wormhole message account.
-    pub wormhole_message: UncheckedAccount<'info>,
+    /// Record of this transfer. The sender can close it to reclaim rent.
+    pub transfer_receipt: Box<Account<'info, TransferReceipt>>,
 
     #[account(
         mut,
@@ -535,7 +1074,7 @@ pub struct RedeemWrappedTransferWithPayload<'info> {
         mut,
         constraint = payer.key() == recipient.key() || payer_token_account.key() == anchor_spl::associated_token::get_associated_token_address(&payer.key(), &token_bridge_wrapped_mint.key()) @ BridgeError::InvalidPayerAta
     )]
-    /// CHECK: Payer's token account.
+    /// CHECK: Payer's ATA, created by the handler if it does not exist.
     pub payer_token_account: UncheckedAccount<'info>,
 
     #[account(
@@ -550,7 +1089,7 @@ pub struct RedeemWrappedTransferWithPayload<'info> {
             &vaa.emitter_chain().to_le_bytes()[..]
         ],
         bump,
-        constraint = foreign_contract.verify(&vaa) @ BridgeError::InvalidForeignContract
+        constraint = foreign_contract.verify(vaa.emitter_chain(), vaa.data().from_address()) @ BridgeError::InvalidForeignContract
     )]
     pub foreign_contract: Box<Account<'info, ForeignContract>>,
 
@@ -568,21 +1107,61 @@ pub struct RedeemWrappedTransferWithPayload<'info> {
 
     #[account(
         mut,
-        associated_token::mint = token_bridge_wrapped_mint,
-        associated_token::authority = recipient
+        seeds = [
+            RegisteredToken::SEED_PREFIX,
+            token_bridge_wrapped_mint.key().as_ref()
+        ],
+        bump,
+        constraint = !registered_token.data_is_empty() @ BridgeError::TokenNotRegistered
     )]
-    pub recipient_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: Token registration, deserialized by the instruction handler.
+    pub registered_token: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            ChainRelayerFee::SEED_PREFIX,
+            &vaa.emitter_chain().to_le_bytes()[..]
+        ],
+        bump,
+    )]
+    /// CHECK: Relayer fee override of the source chain. Empty if there is none.
+    pub chain_relayer_fee: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            TokenRelayerFee::SEED_PREFIX,
+            token_bridge_wrapped_mint.key().as_ref()
+        ],
+        bump,
+    )]
+    /// CHECK: Relayer fee override of the token. Empty if there is none.
+    pub token_relayer_fee: UncheckedAccount<'info>,
 
     #[account(mut)]
-    /// CHECK: Recipient may differ from payer.
+    /// CHECK: Destination of the payload's recipient kind, checked by the
+    /// handler. A missing recipient ATA is created by the handler.
+    pub recipient_token_account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Recipient may differ from payer. For a token account recipient
+    /// kind, this is the account's owner.
     pub recipient: UncheckedAccount<'info>,
 
+    /// CHECK: Program named by a call recipient kind, checked by the handler.
+    /// Required for that kind only.
+    pub recipient_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Transfer call PDA that signs the call into a call recipient
+    /// program, checked by the handler. Required for that kind only.
+    pub transfer_call_signer: Option<UncheckedAccount<'info>>,
+
     #[account(
         init,
         payer = payer,
         seeds = [
             SEED_PREFIX_TMP,
             token_bridge_wrapped_mint.key().as_ref(),
+            &vaa_hash[..],
         ],
         bump,
         token::mint = token_bridge_wrapped_mint,
@@ -590,34 +1169,85 @@ pub struct RedeemWrappedTransferWithPayload<'info> {
     )]
     pub tmp_token_account: Box<Account<'info, TokenAccount>>,
 
-    pub wormhole_program: Program<'info, Wormhole>,
-    pub token_bridge_program: Program<'info, TokenBridge>,
+    #[account(
+        seeds = [GovernorConfig::SEED_PREFIX],
+        bump,
+    )]
+    /// Required if the transfer is delayed.
+    pub governor_config: Option<Box<Account<'info, GovernorConfig>>>,
 
     #[account(
+        init,
+        payer = payer,
         seeds = [
-            token_bridge::WrappedMeta::SEED_PREFIX,
-            token_bridge_wrapped_mint.key().as_ref()
+            PendingRelease::SEED_PREFIX,
+            &vaa_hash[..]
         ],
         bump,
-        seeds::program = token_bridge_program.key
+        space = PendingRelease::MAXIMUM_SIZE
     )]
-    pub token_bridge_wrapped_meta: Box<Account<'info, token_bridge::WrappedMeta>>,
+    /// Required if, and only if, the transfer is delayed.
+    pub pending_release: Option<Box<Account<'info, PendingRelease>>>,
 
     #[account(
-        address = config.token_bridge.config @ BridgeError::InvalidTokenBridgeConfig
+        init,
+        payer = payer,
+        seeds = [
+            SEED_PREFIX_ESCROW,
+            &vaa_hash[..]
+        ],
+        bump,
+        token::mint = token_bridge_wrapped_mint,
+        token::authority = config
     )]
-    pub token_bridge_config: Box<Account<'info, token_bridge::Config>>,
+    /// Holds the recipient's tokens of a delayed transfer.
+    pub escrow_token_account: Option<Box<Account<'info, TokenAccount>>>,
 
     #[account(
+        init,
+        payer = payer,
         seeds = [
-            wormhole::SEED_PREFIX_POSTED_VAA,
-            &vaa_hash
+            RedeemReceipt::SEED_PREFIX,
+            &vaa_hash[..]
         ],
         bump,
-        seeds::program = wormhole_program.key,
-        constraint = vaa.data().to() == crate::ID || vaa.data().to() == config.key() @ BridgeError::InvalidTransferToAddress,
-        constraint = vaa.data().to_chain() == wormhole::CHAIN_ID_SOLANA @ BridgeError::InvalidTransferToChain,
-        constraint = vaa.data().token_chain() != wormhole::CHAIN_ID_SOLANA @ BridgeError::InvalidTransferTokenChain
+        space = RedeemReceipt::MAXIMUM_SIZE
+    )]
+    /// Record of the redeemed transfer.
+    pub receipt: Box<Account<'info, RedeemReceipt>>,
+
+    #[account(mut)]
+    /// Receipt of the outbound transfer a refund sends back, marked refunded.
+    pub refunded_transfer_receipt: Option<Box<Account<'info, TransferReceipt>>>,
+
+    pub wormhole_program: Program<'info, Wormhole>,
+    pub token_bridge_program: Program<'info, TokenBridge>,
+
+    #[account(
+        seeds = [
+            token_bridge::WrappedMeta::SEED_PREFIX,
+            token_bridge_wrapped_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_bridge_program.key
+    )]
+    pub token_bridge_wrapped_meta: Box<Account<'info, token_bridge::WrappedMeta>>,
+
+    #[account(
+        address = config.token_bridge.config @ BridgeError::InvalidTokenBridgeConfig
+    )]
+    pub token_bridge_config: Box<Account<'info, token_bridge::Config>>,
+
+    #[account(
+        seeds = [
+            wormhole::SEED_PREFIX_POSTED_VAA,
+            &vaa_hash
+        ],
+        bump,
+        seeds::program = wormhole_program.key,
+        constraint = vaa.data().to() == crate::ID || vaa.data().to() == config.key() @ BridgeError::InvalidTransferToAddress,
+        constraint = vaa.data().to_chain() == wormhole::CHAIN_ID_SOLANA @ BridgeError::InvalidTransferToChain,
+        constraint = vaa.data().token_chain() != wormhole::CHAIN_ID_SOLANA @ BridgeError::InvalidTransferTokenChain
     )]
     pub vaa: Box<Account<'info, PostedTokenMessage>>,
 
@@ -641,3 +1271,1017 @@ pub struct RedeemWrappedTransferWithPayload<'info> {
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub rent: Sysvar<'info, Rent>,
 }
+
+/// Context for configuring the inbound governor.
+#[derive(Accounts)]
+pub struct SetGovernor<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ BridgeError::OwnerOnly,
+        seeds = [RedeemerConfig::SEED_PREFIX],
+        bump
+    )]
+    pub config: Box<Account<'info, RedeemerConfig>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [GovernorConfig::SEED_PREFIX],
+        bump,
+        space = GovernorConfig::MAXIMUM_SIZE
+    )]
+    pub governor_config: Box<Account<'info, GovernorConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for releasing a delayed inbound transfer.
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32])]
+pub struct ReleasePendingTransfer<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [RedeemerConfig::SEED_PREFIX],
+        bump
+    )]
+    pub config: Box<Account<'info, RedeemerConfig>>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            PendingRelease::SEED_PREFIX,
+            &vaa_hash
+        ],
+        bump,
+    )]
+    pub pending_release: Box<Account<'info, PendingRelease>>,
+
+    #[account(
+        mut,
+        seeds = [
+            RegisteredToken::SEED_PREFIX,
+            pending_release.mint.as_ref()
+        ],
+        bump,
+    )]
+    pub registered_token: Box<Account<'info, RegisteredToken>>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX_ESCROW,
+            &vaa_hash
+        ],
+        bump,
+    )]
+    pub escrow_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = pending_release.recipient_token_account @ BridgeError::InvalidRecipient
+    )]
+    pub recipient_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = pending_release.payer
+    )]
+    /// CHECK: Payer of the delayed redeem, refunded the rent.
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        address = pending_release.recipient @ BridgeError::InvalidRecipient
+    )]
+    /// CHECK: Recipient of the delayed transfer, passed to a call recipient
+    /// program.
+    pub recipient: UncheckedAccount<'info>,
+
+    /// CHECK: Program of a call recipient, checked by the handler. Required
+    /// for that kind only.
+    pub recipient_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Transfer call PDA that signs the call into a call recipient
+    /// program, checked by the handler. Required for that kind only.
+    pub transfer_call_signer: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        address = pending_release.mint
+    )]
+    pub mint: Box<Account<'info, Mint>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Context for holding a delayed inbound transfer for review.
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32])]
+pub struct HoldPendingTransfer<'info> {
+    pub guardian: Signer<'info>,
+
+    #[account(
+        has_one = guardian @ BridgeError::GuardianOnly,
+        seeds = [GovernorConfig::SEED_PREFIX],
+        bump
+    )]
+    pub governor_config: Box<Account<'info, GovernorConfig>>,
+
+    #[account(
+        mut,
+        seeds = [
+            PendingRelease::SEED_PREFIX,
+            &vaa_hash
+        ],
+        bump,
+    )]
+    pub pending_release: Box<Account<'info, PendingRelease>>,
+}
+
+/// Context for cancelling a delayed native token transfer, sending it back
+/// to its origin chain.
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32])]
+pub struct CancelPendingNativeTransfer<'info> {
+    #[account(mut)]
+    /// Guardian, paying for the Wormhole message.
+    pub guardian: Signer<'info>,
+
+    #[account(
+        has_one = guardian @ BridgeError::GuardianOnly,
+        seeds = [GovernorConfig::SEED_PREFIX],
+        bump
+    )]
+    pub governor_config: Box<Account<'info, GovernorConfig>>,
+
+    #[account(
+        seeds = [SenderConfig::SEED_PREFIX],
+        bump
+    )]
+    pub sender_config: Box<Account<'info, SenderConfig>>,
+
+    #[account(
+        seeds = [RedeemerConfig::SEED_PREFIX],
+        bump
+    )]
+    pub redeemer_config: Box<Account<'info, RedeemerConfig>>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            PendingRelease::SEED_PREFIX,
+            &vaa_hash
+        ],
+        bump,
+    )]
+    pub pending_release: Box<Account<'info, PendingRelease>>,
+
+    #[account(
+        mut,
+        address = pending_release.payer
+    )]
+    /// CHECK: Payer of the delayed redeem, refunded the rent.
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            ForeignContract::SEED_PREFIX,
+            &pending_release.emitter_chain.to_le_bytes()[..]
+        ],
+        bump
+    )]
+    pub foreign_contract: Box<Account<'info, ForeignContract>>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX_ESCROW,
+            &vaa_hash
+        ],
+        bump,
+    )]
+    pub escrow_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = pending_release.recipient_token_account @ BridgeError::InvalidRecipient
+    )]
+    /// Recipient's token account, paid the dust Token Bridge cannot carry.
+    pub recipient_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = pending_release.mint
+    )]
+    pub mint: Box<Account<'info, Mint>>,
+
+    pub wormhole_program: Program<'info, Wormhole>,
+    pub token_bridge_program: Program<'info, TokenBridge>,
+
+    #[account(
+        address = sender_config.token_bridge.config @ BridgeError::InvalidTokenBridgeConfig
+    )]
+    pub token_bridge_config: Box<Account<'info, token_bridge::Config>>,
+
+    #[account(
+        mut,
+        seeds = [mint.key().as_ref()],
+        bump,
+        seeds::program = token_bridge_program.key
+    )]
+    pub token_bridge_custody: Account<'info, TokenAccount>,
+
+    #[account(
+        address = sender_config.token_bridge.authority_signer @ BridgeError::InvalidTokenBridgeAuthoritySigner
+    )]
+    /// CHECK: Token Bridge authority signer.
+    pub token_bridge_authority_signer: UncheckedAccount<'info>,
+
+    #[account(
+        address = sender_config.token_bridge.custody_signer @ BridgeError::InvalidTokenBridgeCustodySigner
+    )]
+    /// CHECK: Token Bridge custody signer.
+    pub token_bridge_custody_signer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = sender_config.token_bridge.wormhole_bridge @ BridgeError::InvalidWormholeBridge,
+    )]
+    pub wormhole_bridge: Box<Account<'info, wormhole::BridgeData>>,
+
+    #[account(mut)]
+    /// Wormhole message account, a fresh keypair.
+    pub wormhole_message: Signer<'info>,
+
+    #[account(
+        mut,
+        address = sender_config.token_bridge.emitter @ BridgeError::InvalidTokenBridgeEmitter
+    )]
+    /// CHECK: Token Bridge emitter.
+    pub token_bridge_emitter: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = sender_config.token_bridge.sequence @ BridgeError::InvalidTokenBridgeSequence
+    )]
+    pub token_bridge_sequence: Box<Account<'info, wormhole::SequenceTracker>>,
+
+    #[account(
+        mut,
+        address = sender_config.token_bridge.wormhole_fee_collector @ BridgeError::InvalidWormholeFeeCollector
+    )]
+    pub wormhole_fee_collector: Box<Account<'info, wormhole::FeeCollector>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Context for cancelling a delayed wrapped token transfer, sending it back
+/// to its origin chain.
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32])]
+pub struct CancelPendingWrappedTransfer<'info> {
+    #[account(mut)]
+    /// Guardian, paying for the Wormhole message.
+    pub guardian: Signer<'info>,
+
+    #[account(
+        has_one = guardian @ BridgeError::GuardianOnly,
+        seeds = [GovernorConfig::SEED_PREFIX],
+        bump
+    )]
+    pub governor_config: Box<Account<'info, GovernorConfig>>,
+
+    #[account(
+        seeds = [SenderConfig::SEED_PREFIX],
+        bump
+    )]
+    pub sender_config: Box<Account<'info, SenderConfig>>,
+
+    #[account(
+        seeds = [RedeemerConfig::SEED_PREFIX],
+        bump
+    )]
+    pub redeemer_config: Box<Account<'info, RedeemerConfig>>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            PendingRelease::SEED_PREFIX,
+            &vaa_hash
+        ],
+        bump,
+    )]
+    pub pending_release: Box<Account<'info, PendingRelease>>,
+
+    #[account(
+        mut,
+        address = pending_release.payer
+    )]
+    /// CHECK: Payer of the delayed redeem, refunded the rent.
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            ForeignContract::SEED_PREFIX,
+            &pending_release.emitter_chain.to_le_bytes()[..]
+        ],
+        bump
+    )]
+    pub foreign_contract: Box<Account<'info, ForeignContract>>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX_ESCROW,
+            &vaa_hash
+        ],
+        bump,
+    )]
+    pub escrow_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = pending_release.mint
+    )]
+    pub token_bridge_wrapped_mint: Box<Account<'info, token_bridge::WrappedMint>>,
+
+    pub wormhole_program: Program<'info, Wormhole>,
+    pub token_bridge_program: Program<'info, TokenBridge>,
+
+    #[account(
+        seeds = [
+            token_bridge::WrappedMeta::SEED_PREFIX,
+            token_bridge_wrapped_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_bridge_program.key
+    )]
+    pub token_bridge_wrapped_meta: Box<Account<'info, token_bridge::WrappedMeta>>,
+
+    #[account(
+        mut,
+        address = sender_config.token_bridge.config @ BridgeError::InvalidTokenBridgeConfig
+    )]
+    pub token_bridge_config: Box<Account<'info, token_bridge::Config>>,
+
+    #[account(
+        address = sender_config.token_bridge.authority_signer @ BridgeError::InvalidTokenBridgeAuthoritySigner
+    )]
+    /// CHECK: Token Bridge authority signer.
+    pub token_bridge_authority_signer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = sender_config.token_bridge.wormhole_bridge @ BridgeError::InvalidWormholeBridge,
+    )]
+    pub wormhole_bridge: Box<Account<'info, wormhole::BridgeData>>,
+
+    #[account(mut)]
+    /// Wormhole message account, a fresh keypair.
+    pub wormhole_message: Signer<'info>,
+
+    #[account(
+        mut,
+        address = sender_config.token_bridge.emitter @ BridgeError::InvalidTokenBridgeEmitter
+    )]
+    /// CHECK: Token Bridge emitter.
+    pub token_bridge_emitter: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = sender_config.token_bridge.sequence @ BridgeError::InvalidTokenBridgeSequence
+    )]
+    pub token_bridge_sequence: Box<Account<'info, wormhole::SequenceTracker>>,
+
+    #[account(
+        mut,
+        address = sender_config.token_bridge.wormhole_fee_collector @ BridgeError::InvalidWormholeFeeCollector
+    )]
+    pub wormhole_fee_collector: Box<Account<'info, wormhole::FeeCollector>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Context for configuring refunds of undeliverable inbound transfers.
+#[derive(Accounts)]
+pub struct SetRefundTimeout<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ BridgeError::OwnerOnly,
+        seeds = [RedeemerConfig::SEED_PREFIX],
+        bump
+    )]
+    pub config: Box<Account<'info, RedeemerConfig>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [RefundConfig::SEED_PREFIX],
+        bump,
+        space = RefundConfig::MAXIMUM_SIZE
+    )]
+    pub refund_config: Box<Account<'info, RefundConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for sending an undeliverable native token transfer back to its
+/// origin chain.
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32])]
+pub struct RefundNativeTransfer<'info> {
+    #[account(
+        mut,
+        constraint = payer.key().to_bytes() == *vaa.message().data().recipient()
+            || payer.key() == redeemer_config.owner @ BridgeError::InvalidRefundCaller
+    )]
+    /// The payload's recipient, declining the transfer, or the program owner.
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [SenderConfig::SEED_PREFIX],
+        bump
+    )]
+    pub sender_config: Box<Account<'info, SenderConfig>>,
+
+    #[account(
+        seeds = [RedeemerConfig::SEED_PREFIX],
+        bump
+    )]
+    pub redeemer_config: Box<Account<'info, RedeemerConfig>>,
+
+    #[account(
+        seeds = [RefundConfig::SEED_PREFIX],
+        bump
+    )]
+    pub refund_config: Box<Account<'info, RefundConfig>>,
+
+    #[account(
+        seeds = [
+            ForeignContract::SEED_PREFIX,
+            &vaa.emitter_chain().to_le_bytes()[..]
+        ],
+        bump,
+        constraint = foreign_contract.verify(vaa.emitter_chain(), vaa.data().from_address()) @ BridgeError::InvalidForeignContract
+    )]
+    pub foreign_contract: Box<Account<'info, ForeignContract>>,
+
+    #[account(
+        mut,
+        constraint = mint.key() == vaa.data().mint()
+    )]
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            SEED_PREFIX_TMP,
+            mint.key().as_ref(),
+            &vaa_hash[..],
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = redeemer_config
+    )]
+    pub tmp_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub wormhole_program: Program<'info, Wormhole>,
+    pub token_bridge_program: Program<'info, TokenBridge>,
+
+    #[account(
+        address = sender_config.token_bridge.config @ BridgeError::InvalidTokenBridgeConfig
+    )]
+    pub token_bridge_config: Box<Account<'info, token_bridge::Config>>,
+
+    #[account(
+        seeds = [
+            wormhole::SEED_PREFIX_POSTED_VAA,
+            &vaa_hash
+        ],
+        bump,
+        seeds::program = wormhole_program.key,
+        constraint = vaa.data().to() == crate::ID || vaa.data().to() == redeemer_config.key() @ BridgeError::InvalidTransferToAddress,
+        constraint = vaa.data().to_chain() == wormhole::CHAIN_ID_SOLANA @ BridgeError::InvalidTransferToChain,
+        constraint = vaa.data().token_chain() == wormhole::CHAIN_ID_SOLANA @ BridgeError::InvalidTransferTokenChain
+    )]
+    pub vaa: Box<Account<'info, PostedTokenMessage>>,
+
+    #[account(mut)]
+    /// CHECK: Token Bridge claim account.
+    pub token_bridge_claim: UncheckedAccount<'info>,
+
+    #[account(
+        address = foreign_contract.token_bridge_foreign_endpoint @ BridgeError::InvalidTokenBridgeForeignEndpoint
+    )]
+    pub token_bridge_foreign_endpoint: Box<Account<'info, token_bridge::EndpointRegistration>>,
+
+    #[account(
+        mut,
+        seeds = [mint.key().as_ref()],
+        bump,
+        seeds::program = token_bridge_program.key
+    )]
+    pub token_bridge_custody: Account<'info, TokenAccount>,
+
+    #[account(
+        address = sender_config.token_bridge.authority_signer @ BridgeError::InvalidTokenBridgeAuthoritySigner
+    )]
+    /// CHECK: Token Bridge authority signer.
+    pub token_bridge_authority_signer: UncheckedAccount<'info>,
+
+    #[account(
+        address = sender_config.token_bridge.custody_signer @ BridgeError::InvalidTokenBridgeCustodySigner
+    )]
+    /// CHECK: Token Bridge custody signer.
+    pub token_bridge_custody_signer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = sender_config.token_bridge.wormhole_bridge @ BridgeError::InvalidWormholeBridge,
+    )]
+    pub wormhole_bridge: Box<Account<'info, wormhole::BridgeData>>,
+
+    #[account(mut)]
+    /// Wormhole message account, a fresh keypair.
+    pub wormhole_message: Signer<'info>,
+
+    #[account(
+        mut,
+        address = sender_config.token_bridge.emitter @ BridgeError::InvalidTokenBridgeEmitter
+    )]
+    /// CHECK: Token Bridge emitter.
+    pub token_bridge_emitter: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = sender_config.token_bridge.sequence @ BridgeError::InvalidTokenBridgeSequence
+    )]
+    pub token_bridge_sequence: Box<Account<'info, wormhole::SequenceTracker>>,
+
+    #[account(
+        mut,
+        address = sender_config.token_bridge.wormhole_fee_collector @ BridgeError::InvalidWormholeFeeCollector
+    )]
+    pub wormhole_fee_collector: Box<Account<'info, wormhole::FeeCollector>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Context for sending an undeliverable wrapped token transfer back to its
+/// origin chain.
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32])]
+pub struct RefundWrappedTransfer<'info> {
+    #[account(
+        mut,
+        constraint = payer.key().to_bytes() == *vaa.message().data().recipient()
+            || payer.key() == redeemer_config.owner @ BridgeError::InvalidRefundCaller
+    )]
+    /// The payload's recipient, declining the transfer, or the program owner.
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [SenderConfig::SEED_PREFIX],
+        bump
+    )]
+    pub sender_config: Box<Account<'info, SenderConfig>>,
+
+    #[account(
+        seeds = [RedeemerConfig::SEED_PREFIX],
+        bump
+    )]
+    pub redeemer_config: Box<Account<'info, RedeemerConfig>>,
+
+    #[account(
+        seeds = [RefundConfig::SEED_PREFIX],
+        bump
+    )]
+    pub refund_config: Box<Account<'info, RefundConfig>>,
+
+    #[account(
+        seeds = [
+            ForeignContract::SEED_PREFIX,
+            &vaa.emitter_chain().to_le_bytes()[..]
+        ],
+        bump,
+        constraint = foreign_contract.verify(vaa.emitter_chain(), vaa.data().from_address()) @ BridgeError::InvalidForeignContract
+    )]
+    pub foreign_contract: Box<Account<'info, ForeignContract>>,
+
+    #[account(
+        mut,
+        seeds = [
+            token_bridge::WrappedMint::SEED_PREFIX,
+            &vaa.data().token_chain().to_be_bytes(),
+            vaa.data().token_address()
+        ],
+        bump,
+        seeds::program = token_bridge_program.key
+    )]
+    pub token_bridge_wrapped_mint: Box<Account<'info, token_bridge::WrappedMint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            SEED_PREFIX_TMP,
+            token_bridge_wrapped_mint.key().as_ref(),
+            &vaa_hash[..],
+        ],
+        bump,
+        token::mint = token_bridge_wrapped_mint,
+        token::authority = redeemer_config
+    )]
+    pub tmp_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub wormhole_program: Program<'info, Wormhole>,
+    pub token_bridge_program: Program<'info, TokenBridge>,
+
+    #[account(
+        seeds = [
+            token_bridge::WrappedMeta::SEED_PREFIX,
+            token_bridge_wrapped_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_bridge_program.key
+    )]
+    pub token_bridge_wrapped_meta: Box<Account<'info, token_bridge::WrappedMeta>>,
+
+    #[account(
+        mut,
+        address = sender_config.token_bridge.config @ BridgeError::InvalidTokenBridgeConfig
+    )]
+    pub token_bridge_config: Box<Account<'info, token_bridge::Config>>,
+
+    #[account(
+        seeds = [
+            wormhole::SEED_PREFIX_POSTED_VAA,
+            &vaa_hash
+        ],
+        bump,
+        seeds::program = wormhole_program.key,
+        constraint = vaa.data().to() == crate::ID || vaa.data().to() == redeemer_config.key() @ BridgeError::InvalidTransferToAddress,
+        constraint = vaa.data().to_chain() == wormhole::CHAIN_ID_SOLANA @ BridgeError::InvalidTransferToChain,
+        constraint = vaa.data().token_chain() != wormhole::CHAIN_ID_SOLANA @ BridgeError::InvalidTransferTokenChain
+    )]
+    pub vaa: Box<Account<'info, PostedTokenMessage>>,
+
+    #[account(mut)]
+    /// CHECK: Token Bridge claim account.
+    pub token_bridge_claim: UncheckedAccount<'info>,
+
+    #[account(
+        address = foreign_contract.token_bridge_foreign_endpoint @ BridgeError::InvalidTokenBridgeForeignEndpoint
+    )]
+    pub token_bridge_foreign_endpoint: Box<Account<'info, token_bridge::EndpointRegistration>>,
+
+    #[account(
+        address = redeemer_config.token_bridge.mint_authority @ BridgeError::InvalidTokenBridgeMintAuthority
+    )]
+    /// CHECK: Token Bridge mint authority.
+    pub token_bridge_mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        address = sender_config.token_bridge.authority_signer @ BridgeError::InvalidTokenBridgeAuthoritySigner
+    )]
+    /// CHECK: Token Bridge authority signer.
+    pub token_bridge_authority_signer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = sender_config.token_bridge.wormhole_bridge @ BridgeError::InvalidWormholeBridge,
+    )]
+    pub wormhole_bridge: Box<Account<'info, wormhole::BridgeData>>,
+
+    #[account(mut)]
+    /// Wormhole message account, a fresh keypair.
+    pub wormhole_message: Signer<'info>,
+
+    #[account(
+        mut,
+        address = sender_config.token_bridge.emitter @ BridgeError::InvalidTokenBridgeEmitter
+    )]
+    /// CHECK: Token Bridge emitter.
+    pub token_bridge_emitter: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = sender_config.token_bridge.sequence @ BridgeError::InvalidTokenBridgeSequence
+    )]
+    pub token_bridge_sequence: Box<Account<'info, wormhole::SequenceTracker>>,
+
+    #[account(
+        mut,
+        address = sender_config.token_bridge.wormhole_fee_collector @ BridgeError::InvalidWormholeFeeCollector
+    )]
+    pub wormhole_fee_collector: Box<Account<'info, wormhole::FeeCollector>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+#[instruction(wormhole_message: Pubkey)]
+pub struct SetTransferStatus<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ BridgeError::OwnerOnly,
+        seeds = [SenderConfig::SEED_PREFIX],
+        bump
+    )]
+    pub config: Box<Account<'info, SenderConfig>>,
+
+    #[account(
+        mut,
+        seeds = [
+            TransferReceipt::SEED_PREFIX,
+            wormhole_message.as_ref()
+        ],
+        bump
+    )]
+    pub transfer_receipt: Box<Account<'info, TransferReceipt>>,
+}
+
+#[derive(Accounts)]
+#[instruction(wormhole_message: Pubkey)]
+pub struct CloseTransferReceipt<'info> {
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    #[account(
+        mut,
+        close = sender,
+        has_one = sender @ BridgeError::NotReceiptSender,
+        seeds = [
+            TransferReceipt::SEED_PREFIX,
+            wormhole_message.as_ref()
+        ],
+        bump
+    )]
+    pub transfer_receipt: Box<Account<'info, TransferReceipt>>,
+}
+
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32])]
+pub struct CloseRedeemReceipt<'info> {
+    #[account(
+        constraint = closer.key() == receipt.recipient
+            || config.is_owner(&closer.key()) @ BridgeError::InvalidReceiptCloser
+    )]
+    /// The transfer's recipient, or the program owner.
+    pub closer: Signer<'info>,
+
+    #[account(
+        seeds = [RedeemerConfig::SEED_PREFIX],
+        bump
+    )]
+    pub config: Box<Account<'info, RedeemerConfig>>,
+
+    #[account(
+        mut,
+        close = relayer,
+        seeds = [
+            RedeemReceipt::SEED_PREFIX,
+            &vaa_hash
+        ],
+        bump
+    )]
+    pub receipt: Box<Account<'info, RedeemReceipt>>,
+
+    #[account(
+        mut,
+        address = receipt.relayer
+    )]
+    /// CHECK: Relayer who paid the receipt's rent, refunded on close.
+    pub relayer: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AttestToken<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ BridgeError::OwnerOnly,
+        seeds = [SenderConfig::SEED_PREFIX],
+        bump
+    )]
+    pub config: Box<Account<'info, SenderConfig>>,
+
+    /// Solana mint to attest.
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [
+            token_bridge::WrappedMeta::SEED_PREFIX,
+            mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_bridge_program.key
+    )]
+    /// CHECK: Token Bridge wrapped metadata. Must be empty, i.e. the mint is
+    /// native to Solana; checked by Token Bridge.
+    pub token_bridge_wrapped_meta: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex metadata of the mint, checked by Token Bridge.
+    pub spl_metadata: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = config.token_bridge.config @ BridgeError::InvalidTokenBridgeConfig
+    )]
+    /// CHECK: Token Bridge config.
+    pub token_bridge_config: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = config.token_bridge.wormhole_bridge @ BridgeError::InvalidWormholeBridge,
+    )]
+    pub wormhole_bridge: Box<Account<'info, wormhole::BridgeData>>,
+
+    #[account(mut)]
+    /// Wormhole message account, a fresh keypair.
+    pub wormhole_message: Signer<'info>,
+
+    #[account(
+        address = config.token_bridge.emitter @ BridgeError::InvalidTokenBridgeEmitter
+    )]
+    /// CHECK: Token Bridge emitter.
+    pub token_bridge_emitter: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = config.token_bridge.sequence @ BridgeError::InvalidTokenBridgeSequence
+    )]
+    pub token_bridge_sequence: Box<Account<'info, wormhole::SequenceTracker>>,
+
+    #[account(
+        mut,
+        address = config.token_bridge.wormhole_fee_collector @ BridgeError::InvalidWormholeFeeCollector
+    )]
+    pub wormhole_fee_collector: Box<Account<'info, wormhole::FeeCollector>>,
+
+    pub wormhole_program: Program<'info, Wormhole>,
+    pub token_bridge_program: Program<'info, TokenBridge>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32])]
+pub struct CreateWrapped<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ BridgeError::OwnerOnly,
+        seeds = [RedeemerConfig::SEED_PREFIX],
+        bump
+    )]
+    pub config: Box<Account<'info, RedeemerConfig>>,
+
+    #[account(
+        seeds = [
+            wormhole::SEED_PREFIX_POSTED_VAA,
+            &vaa_hash
+        ],
+        bump,
+        seeds::program = wormhole_program.key,
+        constraint = vaa.data().token_chain != wormhole::CHAIN_ID_SOLANA @ BridgeError::InvalidTransferTokenChain
+    )]
+    /// Posted `AssetMeta` VAA of the foreign token.
+    pub vaa: Box<Account<'info, token_bridge::PostedAssetMeta>>,
+
+    #[account(mut)]
+    /// CHECK: Token Bridge claim account.
+    pub token_bridge_claim: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            &vaa.emitter_chain().to_be_bytes(),
+            vaa.emitter_address()
+        ],
+        bump,
+        seeds::program = token_bridge_program.key
+    )]
+    pub token_bridge_foreign_endpoint: Box<Account<'info, token_bridge::EndpointRegistration>>,
+
+    #[account(
+        mut,
+        seeds = [
+            token_bridge::WrappedMint::SEED_PREFIX,
+            &vaa.data().token_chain.to_be_bytes(),
+            &vaa.data().token_address
+        ],
+        bump,
+        seeds::program = token_bridge_program.key
+    )]
+    /// CHECK: Wrapped mint, created by Token Bridge.
+    pub token_bridge_wrapped_mint: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            token_bridge::WrappedMeta::SEED_PREFIX,
+            token_bridge_wrapped_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_bridge_program.key
+    )]
+    /// CHECK: Wrapped metadata, created by Token Bridge.
+    pub token_bridge_wrapped_meta: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Metaplex metadata of the wrapped mint, created by Token Bridge.
+    pub spl_metadata: UncheckedAccount<'info>,
+
+    #[account(
+        address = config.token_bridge.config @ BridgeError::InvalidTokenBridgeConfig
+    )]
+    pub token_bridge_config: Box<Account<'info, token_bridge::Config>>,
+
+    #[account(
+        address = config.token_bridge.mint_authority @ BridgeError::InvalidTokenBridgeMintAuthority
+    )]
+    /// CHECK: Token Bridge mint authority.
+    pub token_bridge_mint_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex token metadata program, checked by Token Bridge.
+    pub spl_metadata_program: UncheckedAccount<'info>,
+
+    pub wormhole_program: Program<'info, Wormhole>,
+    pub token_bridge_program: Program<'info, TokenBridge>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+#[instruction(source_chain: u16)]
+pub struct QuoteInboundTransfer<'info> {
+    #[account(
+        seeds = [RedeemerConfig::SEED_PREFIX],
+        bump
+    )]
+    pub config: Box<Account<'info, RedeemerConfig>>,
+
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [
+            RegisteredToken::SEED_PREFIX,
+            mint.key().as_ref()
+        ],
+        bump,
+    )]
+    pub registered_token: Box<Account<'info, RegisteredToken>>,
+
+    #[account(
+        seeds = [
+            ChainRelayerFee::SEED_PREFIX,
+            &source_chain.to_le_bytes()[..]
+        ],
+        bump,
+    )]
+    /// CHECK: Relayer fee override of the source chain. Empty if there is none.
+    pub chain_relayer_fee: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            TokenRelayerFee::SEED_PREFIX,
+            mint.key().as_ref()
+        ],
+        bump,
+    )]
+    /// CHECK: Relayer fee override of the token. Empty if there is none.
+    pub token_relayer_fee: UncheckedAccount<'info>,
+
+    /// CHECK: Recipient wallet, read for its balance.
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        address = anchor_spl::associated_token::get_associated_token_address(&recipient.key(), &mint.key())
+    )]
+    /// CHECK: Recipient's ATA. Empty if the relayer would create it.
+    pub recipient_token_account: UncheckedAccount<'info>,
+}
@@ -67,4 +67,97 @@ pub enum BridgeError {
 
     #[msg("InvalidTokenBridgeMintAuthority")]
     InvalidTokenBridgeMintAuthority,
+
+    #[msg("TokenNotRegistered")]
+    TokenNotRegistered,
+
+    #[msg("OutboundTransfersDisabled")]
+    OutboundTransfersDisabled,
+
+    #[msg("InboundTransfersDisabled")]
+    InboundTransfersDisabled,
+
+    #[msg("InvalidTokenLimits")]
+    InvalidTokenLimits,
+
+    #[msg("AmountBelowMinimum")]
+    AmountBelowMinimum,
+
+    #[msg("AmountAboveMaximum")]
+    AmountAboveMaximum,
+
+    #[msg("OutboundLimitExceeded")]
+    OutboundLimitExceeded,
+
+    #[msg("ChainOutboundLimitExceeded")]
+    ChainOutboundLimitExceeded,
+
+    #[msg("InvalidReleaseDelay")]
+    InvalidReleaseDelay,
+
+    #[msg("GovernorNotConfigured")]
+    GovernorNotConfigured,
+
+    #[msg("PendingReleaseRequired")]
+    PendingReleaseRequired,
+
+    #[msg("UnexpectedPendingRelease")]
+    UnexpectedPendingRelease,
+
+    #[msg("ReleaseNotDue")]
+    ReleaseNotDue,
+
+    #[msg("GuardianOnly")]
+    GuardianOnly,
+
+    #[msg("TransferHeld")]
+    TransferHeld,
+
+    #[msg("InvalidAppData")]
+    InvalidAppData,
+
+    #[msg("NotNativeMint")]
+    NotNativeMint,
+
+    #[msg("InvalidRecipientAta")]
+    InvalidRecipientAta,
+
+    #[msg("InvalidRecipientTokenAccount")]
+    InvalidRecipientTokenAccount,
+
+    #[msg("InvalidRecipientKind")]
+    InvalidRecipientKind,
+
+    #[msg("InvalidRecipientProgram")]
+    InvalidRecipientProgram,
+
+    #[msg("InvalidRefundTimeout")]
+    InvalidRefundTimeout,
+
+    #[msg("NotRefundable")]
+    NotRefundable,
+
+    #[msg("RefundNotDue")]
+    RefundNotDue,
+
+    #[msg("NotReceiptSender")]
+    NotReceiptSender,
+
+    #[msg("InvalidReceiptCloser")]
+    InvalidReceiptCloser,
+
+    #[msg("InexactAmount")]
+    InexactAmount,
+
+    #[msg("InvalidTransferCallSigner")]
+    InvalidTransferCallSigner,
+
+    #[msg("InvalidGasDropoff")]
+    InvalidGasDropoff,
+
+    #[msg("InvalidRefundCaller")]
+    InvalidRefundCaller,
+
+    #[msg("InvalidTransferReceipt")]
+    InvalidTransferReceipt,
 }
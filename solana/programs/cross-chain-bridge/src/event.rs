@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+/// Emitted when a transfer is redeemed, including when it is delayed by the
+/// governor.
+#[event]
+pub struct TransferRedeemed {
+    /// Chain the transfer was sent from.
+    pub emitter_chain: u16,
+    /// Token Bridge sequence of the transfer.
+    pub sequence: u64,
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    /// Amount transferred, relayer fee included.
+    pub amount: u64,
+    /// Relayer fee paid to the payer, gas drop-off reimbursement included.
+    pub relayer_amount: u64,
+    /// Lamports the relayer dropped off to the recipient.
+    pub gas_dropoff: u64,
+    /// Whether the recipient's tokens are held by the governor.
+    pub delayed: bool,
+    /// Origin sender (zero for legacy payloads).
+    pub sender: [u8; 32],
+    /// Memo (zero for legacy payloads).
+    pub memo: [u8; 32],
+    /// App data (empty for legacy payloads).
+    pub app_data: Vec<u8>,
+}
+
+/// Emitted when an undeliverable transfer is sent back to its origin chain.
+#[event]
+pub struct TransferRefunded {
+    /// Chain the transfer was sent from, and refunded to.
+    pub emitter_chain: u16,
+    /// Token Bridge sequence of the refunded transfer.
+    pub sequence: u64,
+    pub mint: Pubkey,
+    /// Amount sent back, in the mint's decimals.
+    pub amount: u64,
+    /// Origin chain address the refund is paid to.
+    pub refund_recipient: [u8; 32],
+}
@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use wormhole_anchor_sdk::token_bridge;
+
+use crate::state::RegisteredToken;
+
+/// An inbound transfer, split between the recipient and the relayer the same
+/// way by the redeem instructions and `quote_inbound_transfer`.
+pub struct InboundTransfer {
+    /// Amount completed by Token Bridge, in the mint's units.
+    pub amount: u64,
+    /// Decimals of the mint.
+    pub decimals: u8,
+    /// Relayer fee from the owner's settings, resolved for `amount`.
+    pub relayer_fee: u64,
+    /// Sender-set relayer fee cap (normalized), if any.
+    pub max_relayer_fee: Option<u64>,
+    /// Gas drop-off requested by the sender, in lamports.
+    pub gas_dropoff: u64,
+}
+
+impl InboundTransfer {
+    /// Split out the relayer's share: the relayer fee plus the `ata_rent` of
+    /// a recipient ATA the relayer created and the cost of the gas drop-off,
+    /// both at the owner-set exchange rate. The sender's cap applies to the
+    /// whole share; a drop-off whose cost does not fit is skipped. An empty
+    /// recipient wallet must receive at least `rent_minimum`. Returns the
+    /// relayer's amount and the lamports to drop off.
+    pub fn relayer_payout(
+        &self,
+        registered_token: &RegisteredToken,
+        ata_rent: u64,
+        recipient_lamports: u64,
+        rent_minimum: u64,
+    ) -> (u64, u64) {
+        let max_payout = match self.max_relayer_fee {
+            Some(max_relayer_fee) => token_bridge::denormalize_amount(
+                max_relayer_fee.min(token_bridge::normalize_amount(self.amount, self.decimals)),
+                self.decimals,
+            ),
+            None => self.amount,
+        };
+        let relayer_amount = self
+            .relayer_fee
+            .saturating_add(registered_token.lamports_in_tokens(ata_rent))
+            .min(max_payout);
+
+        let (gas_dropoff, gas_dropoff_cost) = registered_token.gas_dropoff(
+            self.gas_dropoff,
+            max_payout - relayer_amount,
+            recipient_lamports,
+            rent_minimum,
+        );
+
+        (relayer_amount + gas_dropoff_cost, gas_dropoff)
+    }
+}
+
+/// Breakdown of a transfer redeemed on Solana by a relayer, as returned by
+/// `quote_inbound_transfer`. Amounts are in the mint's (denormalized) units.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, PartialEq, Eq)]
+pub struct TransferQuote {
+    /// Amount Token Bridge carries after truncation to 8 decimals.
+    pub amount: u64,
+    /// Truncation dust, never taken from the sender.
+    pub dust: u64,
+    /// Amount paid to the relayer: the relayer fee, ATA rent recoup and gas
+    /// drop-off cost.
+    pub relayer_fee: u64,
+    /// Lamports dropped off to the recipient.
+    pub gas_dropoff: u64,
+    /// Amount the recipient receives.
+    pub recipient_amount: u64,
+}
+
+#[test]
+fn inbound_transfer_relayer_payout() {
+    const RENT_MINIMUM: u64 = 890_880;
+
+    // 1 SOL is worth 1_000_000 tokens of 9 decimals.
+    let token = RegisteredToken {
+        sol_exchange_rate: 1_000_000,
+        max_gas_dropoff: 1_000_000_000,
+        ..Default::default()
+    };
+    let transfer = |max_relayer_fee, gas_dropoff| InboundTransfer {
+        amount: 1_000_000,
+        decimals: 9,
+        relayer_fee: 10_000,
+        max_relayer_fee,
+        gas_dropoff,
+    };
+
+    assert_eq!(
+        transfer(None, 0).relayer_payout(&token, 0, RENT_MINIMUM, RENT_MINIMUM),
+        (10_000, 0)
+    );
+
+    // The cap is normalized: 100 is 1_000 units of 9 decimals.
+    assert_eq!(
+        transfer(Some(100), 0).relayer_payout(&token, 0, RENT_MINIMUM, RENT_MINIMUM),
+        (1_000, 0)
+    );
+
+    // ATA rent is recouped within the cap, even when the cap is smaller
+    // than the rent.
+    assert_eq!(
+        transfer(Some(2_000), 0).relayer_payout(&token, 2_039_280, RENT_MINIMUM, RENT_MINIMUM),
+        (10_000 + 2_039, 0)
+    );
+    assert_eq!(
+        transfer(Some(100), 0).relayer_payout(&token, 2_039_280, RENT_MINIMUM, RENT_MINIMUM),
+        (1_000, 0)
+    );
+
+    // A drop-off costing more than the cap leaves is skipped.
+    assert_eq!(
+        transfer(Some(2_000), 100_000_000).relayer_payout(&token, 0, RENT_MINIMUM, RENT_MINIMUM),
+        (10_000, 0)
+    );
+
+    // The drop-off is paid for from what is left, and raised to the rent
+    // minimum for an empty wallet.
+    assert_eq!(
+        transfer(None, 100_000_000).relayer_payout(&token, 0, RENT_MINIMUM, RENT_MINIMUM),
+        (10_000 + 100_000, 100_000_000)
+    );
+    assert_eq!(
+        transfer(None, 1).relayer_payout(&token, 0, 0, RENT_MINIMUM),
+        (10_000 + 890, RENT_MINIMUM)
+    );
+
+    // The relayer never takes more than the amount.
+    let mut all = transfer(None, 0);
+    all.relayer_fee = all.amount;
+    assert_eq!(
+        all.relayer_payout(&token, 2_039_280, RENT_MINIMUM, RENT_MINIMUM),
+        (1_000_000, 0)
+    );
+}
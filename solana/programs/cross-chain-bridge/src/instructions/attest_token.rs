@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use wormhole_anchor_sdk::token_bridge;
+
+use crate::context::AttestToken;
+
+pub fn handler(ctx: Context<AttestToken>, batch_id: u32) -> Result<()> {
+    token_bridge::attest_token(
+        CpiContext::new(
+            ctx.accounts.token_bridge_program.to_account_info(),
+            token_bridge::AttestToken {
+                payer: ctx.accounts.owner.to_account_info(),
+                config: ctx.accounts.token_bridge_config.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                wrapped_metadata: ctx.accounts.token_bridge_wrapped_meta.to_account_info(),
+                spl_metadata: ctx.accounts.spl_metadata.to_account_info(),
+                wormhole_bridge: ctx.accounts.wormhole_bridge.to_account_info(),
+                wormhole_message: ctx.accounts.wormhole_message.to_account_info(),
+                wormhole_emitter: ctx.accounts.token_bridge_emitter.to_account_info(),
+                wormhole_sequence: ctx.accounts.token_bridge_sequence.to_account_info(),
+                wormhole_fee_collector: ctx.accounts.wormhole_fee_collector.to_account_info(),
+                clock: ctx.accounts.clock.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                wormhole_program: ctx.accounts.wormhole_program.to_account_info(),
+            },
+        ),
+        batch_id,
+    )?;
+
+    msg!(
+        "Token attested: {}, sequence {}",
+        ctx.accounts.mint.key(),
+        ctx.accounts.token_bridge_sequence.value()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+use wormhole_anchor_sdk::token_bridge;
+
+use crate::{
+    context::CancelPendingNativeTransfer,
+    error::BridgeError,
+    event::TransferRefunded,
+    state::{RedeemerConfig, SenderConfig},
+    token,
+};
+
+pub fn handler(ctx: Context<CancelPendingNativeTransfer>, _vaa_hash: [u8; 32]) -> Result<()> {
+    let pending_release = &ctx.accounts.pending_release;
+    let payload = pending_release.refund_message()?.try_to_vec()?;
+
+    // Token Bridge truncates amounts to 8 decimals. The dust cannot be sent
+    // back, so it goes to the recipient.
+    let decimals = ctx.accounts.mint.decimals;
+    let escrowed = ctx.accounts.escrow_token_account.amount;
+    let amount = token_bridge::truncate_amount(escrowed, decimals);
+    require!(amount > 0, BridgeError::ZeroBridgeAmount);
+
+    let redeemer_seeds = &[
+        RedeemerConfig::SEED_PREFIX.as_ref(),
+        &[ctx.accounts.redeemer_config.bump],
+    ];
+    let sender_seeds = &[
+        SenderConfig::SEED_PREFIX.as_ref(),
+        &[ctx.accounts.sender_config.bump],
+    ];
+
+    if escrowed > amount {
+        token::transfer_checked(
+            &ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.escrow_token_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.recipient_token_account.to_account_info(),
+            ctx.accounts.redeemer_config.to_account_info(),
+            escrowed - amount,
+            decimals,
+            &[&redeemer_seeds[..]],
+        )?;
+    }
+
+    token_interface::approve(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::Approve {
+                to: ctx.accounts.escrow_token_account.to_account_info(),
+                delegate: ctx.accounts.token_bridge_authority_signer.to_account_info(),
+                authority: ctx.accounts.redeemer_config.to_account_info(),
+            },
+            &[&redeemer_seeds[..]],
+        ),
+        amount,
+    )?;
+
+    token_bridge::transfer_native_with_payload(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_bridge_program.to_account_info(),
+            token_bridge::TransferNativeWithPayload {
+                payer: ctx.accounts.guardian.to_account_info(),
+                config: ctx.accounts.token_bridge_config.to_account_info(),
+                from: ctx.accounts.escrow_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                custody: ctx.accounts.token_bridge_custody.to_account_info(),
+                authority_signer: ctx.accounts.token_bridge_authority_signer.to_account_info(),
+                custody_signer: ctx.accounts.token_bridge_custody_signer.to_account_info(),
+                wormhole_bridge: ctx.accounts.wormhole_bridge.to_account_info(),
+                wormhole_message: ctx.accounts.wormhole_message.to_account_info(),
+                wormhole_emitter: ctx.accounts.token_bridge_emitter.to_account_info(),
+                wormhole_sequence: ctx.accounts.token_bridge_sequence.to_account_info(),
+                wormhole_fee_collector: ctx.accounts.wormhole_fee_collector.to_account_info(),
+                clock: ctx.accounts.clock.to_account_info(),
+                sender: ctx.accounts.sender_config.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                wormhole_program: ctx.accounts.wormhole_program.to_account_info(),
+            },
+            &[&sender_seeds[..]],
+        ),
+        0,
+        amount,
+        ctx.accounts.foreign_contract.peer.address,
+        pending_release.emitter_chain,
+        payload,
+        &ctx.program_id.key(),
+    )?;
+
+    emit!(TransferRefunded {
+        emitter_chain: pending_release.emitter_chain,
+        sequence: pending_release.sequence,
+        mint: ctx.accounts.mint.key(),
+        amount,
+        refund_recipient: pending_release.refund_recipient,
+    });
+
+    msg!(
+        "Pending transfer cancelled: {} refunded to chain {}",
+        amount,
+        pending_release.emitter_chain
+    );
+
+    token_interface::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token_interface::CloseAccount {
+            account: ctx.accounts.escrow_token_account.to_account_info(),
+            destination: ctx.accounts.rent_payer.to_account_info(),
+            authority: ctx.accounts.redeemer_config.to_account_info(),
+        },
+        &[&redeemer_seeds[..]],
+    ))
+}
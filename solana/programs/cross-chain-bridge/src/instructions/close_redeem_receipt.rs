@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+use crate::context::CloseRedeemReceipt;
+
+pub fn handler(ctx: Context<CloseRedeemReceipt>, _vaa_hash: [u8; 32]) -> Result<()> {
+    msg!("Redeem receipt {} closed", ctx.accounts.receipt.sequence);
+
+    Ok(())
+}
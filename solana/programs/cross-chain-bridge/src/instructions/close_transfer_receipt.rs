@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+use crate::context::CloseTransferReceipt;
+
+pub fn handler(ctx: Context<CloseTransferReceipt>, _wormhole_message: Pubkey) -> Result<()> {
+    msg!(
+        "Transfer receipt {} closed",
+        ctx.accounts.transfer_receipt.sequence
+    );
+
+    Ok(())
+}
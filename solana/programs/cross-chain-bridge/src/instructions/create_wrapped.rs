@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use wormhole_anchor_sdk::token_bridge;
+
+use crate::context::CreateWrapped;
+
+pub fn handler(ctx: Context<CreateWrapped>, _vaa_hash: [u8; 32]) -> Result<()> {
+    token_bridge::create_wrapped(CpiContext::new(
+        ctx.accounts.token_bridge_program.to_account_info(),
+        token_bridge::CreateWrapped {
+            payer: ctx.accounts.owner.to_account_info(),
+            config: ctx.accounts.token_bridge_config.to_account_info(),
+            foreign_endpoint: ctx.accounts.token_bridge_foreign_endpoint.to_account_info(),
+            vaa: ctx.accounts.vaa.to_account_info(),
+            claim: ctx.accounts.token_bridge_claim.to_account_info(),
+            wrapped_mint: ctx.accounts.token_bridge_wrapped_mint.to_account_info(),
+            wrapped_metadata: ctx.accounts.token_bridge_wrapped_meta.to_account_info(),
+            spl_metadata: ctx.accounts.spl_metadata.to_account_info(),
+            mint_authority: ctx.accounts.token_bridge_mint_authority.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            spl_metadata_program: ctx.accounts.spl_metadata_program.to_account_info(),
+            wormhole_program: ctx.accounts.wormhole_program.to_account_info(),
+        },
+    ))?;
+
+    let asset_meta = ctx.accounts.vaa.data();
+    msg!(
+        "Wrapped mint created: {} (chain {}, decimals {})",
+        ctx.accounts.token_bridge_wrapped_mint.key(),
+        asset_meta.token_chain,
+        asset_meta.decimals
+    );
+
+    Ok(())
+}
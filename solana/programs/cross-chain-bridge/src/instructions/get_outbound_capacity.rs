@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    context::GetOutboundCapacity,
+    state::{OutboundCapacity, OutboundChainLimit},
+};
+
+pub fn handler(ctx: Context<GetOutboundCapacity>, _chain: u16) -> Result<OutboundCapacity> {
+    let now = Clock::get()?.unix_timestamp;
+    let registered_token = &ctx.accounts.registered_token;
+
+    let chain_capacity = match OutboundChainLimit::load(&ctx.accounts.outbound_chain_limit)? {
+        Some(chain_limit) => chain_limit.limit.capacity(now),
+        None => u64::MAX,
+    };
+
+    Ok(OutboundCapacity {
+        min_amount: registered_token.min_amount,
+        max_amount: match registered_token.max_amount {
+            0 => u64::MAX,
+            max_amount => max_amount,
+        },
+        token_capacity: registered_token.outbound_limit.capacity(now),
+        chain_capacity,
+    })
+}
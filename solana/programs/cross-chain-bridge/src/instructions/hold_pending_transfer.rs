@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+use crate::context::HoldPendingTransfer;
+
+pub fn handler(ctx: Context<HoldPendingTransfer>, _vaa_hash: [u8; 32], held: bool) -> Result<()> {
+    let pending_release = &mut ctx.accounts.pending_release;
+    pending_release.held = held;
+
+    msg!(
+        "Pending transfer {}: {} for {}",
+        if held { "held" } else { "unheld" },
+        pending_release.amount,
+        pending_release.recipient
+    );
+
+    Ok(())
+}
@@ -1,15 +1,61 @@
 pub mod initialize;
 pub mod register_foreign_contract;
 pub mod update_relayer_fee;
+pub mod set_chain_relayer_fee;
+pub mod set_token_relayer_fee;
 pub mod send_native_tokens;
+pub mod send_sol;
 pub mod redeem_native_transfer;
 pub mod send_wrapped_tokens;
 pub mod redeem_wrapped_transfer;
+pub mod register_token;
+pub mod set_token_limits;
+pub mod set_outbound_chain_limit;
+pub mod get_outbound_capacity;
+pub mod set_inbound_limits;
+pub mod set_sol_exchange_rate;
+pub mod set_governor;
+pub mod release_pending_transfer;
+pub mod hold_pending_transfer;
+pub mod cancel_pending_native_transfer;
+pub mod cancel_pending_wrapped_transfer;
+pub mod set_refund_timeout;
+pub mod refund_native_transfer;
+pub mod refund_wrapped_transfer;
+pub mod set_transfer_status;
+pub mod close_transfer_receipt;
+pub mod close_redeem_receipt;
+pub mod attest_token;
+pub mod create_wrapped;
+pub mod quote_inbound_transfer;
 
 pub use initialize::*;
 pub use register_foreign_contract::*;
 pub use update_relayer_fee::*;
+pub use set_chain_relayer_fee::*;
+pub use set_token_relayer_fee::*;
 pub use send_native_tokens::*;
+pub use send_sol::*;
 pub use redeem_native_transfer::*;
 pub use send_wrapped_tokens::*;
 pub use redeem_wrapped_transfer::*;
+pub use register_token::*;
+pub use set_token_limits::*;
+pub use set_outbound_chain_limit::*;
+pub use get_outbound_capacity::*;
+pub use set_inbound_limits::*;
+pub use set_sol_exchange_rate::*;
+pub use set_governor::*;
+pub use release_pending_transfer::*;
+pub use hold_pending_transfer::*;
+pub use cancel_pending_native_transfer::*;
+pub use cancel_pending_wrapped_transfer::*;
+pub use set_refund_timeout::*;
+pub use refund_native_transfer::*;
+pub use refund_wrapped_transfer::*;
+pub use set_transfer_status::*;
+pub use close_transfer_receipt::*;
+pub use close_redeem_receipt::*;
+pub use attest_token::*;
+pub use create_wrapped::*;
+pub use quote_inbound_transfer::*;
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use wormhole_anchor_sdk::token_bridge;
+
+use crate::{
+    context::QuoteInboundTransfer,
+    inbound::{InboundTransfer, TransferQuote},
+    state::{ChainRelayerFee, TokenRelayerFee},
+};
+
+pub fn handler(
+    ctx: Context<QuoteInboundTransfer>,
+    _source_chain: u16,
+    amount: u64,
+    max_relayer_fee: Option<u64>,
+    gas_dropoff: u64,
+) -> Result<TransferQuote> {
+    let decimals = ctx.accounts.mint.decimals;
+    let truncated_amount = token_bridge::truncate_amount(amount, decimals);
+
+    let transfer = InboundTransfer {
+        amount: truncated_amount,
+        decimals,
+        relayer_fee: ctx.accounts.config.resolve_relayer_amount(
+            truncated_amount,
+            ChainRelayerFee::load(&ctx.accounts.chain_relayer_fee)?.as_ref(),
+            TokenRelayerFee::load(&ctx.accounts.token_relayer_fee)?.as_ref(),
+        ),
+        max_relayer_fee,
+        gas_dropoff,
+    };
+    // A missing recipient ATA is created by the relayer, who recoups its rent.
+    let ata_rent = if ctx.accounts.recipient_token_account.data_is_empty() {
+        Rent::get()?.minimum_balance(TokenAccount::LEN)
+    } else {
+        0
+    };
+    let (relayer_fee, gas_dropoff) = transfer.relayer_payout(
+        &ctx.accounts.registered_token,
+        ata_rent,
+        ctx.accounts.recipient.lamports(),
+        Rent::get()?.minimum_balance(0),
+    );
+
+    Ok(TransferQuote {
+        amount: truncated_amount,
+        dust: amount - truncated_amount,
+        relayer_fee,
+        gas_dropoff,
+        recipient_amount: truncated_amount - relayer_fee,
+    })
+}
@@ -1,28 +1,54 @@
 use anchor_lang::prelude::*;
-use wormhole_anchor_sdk::token_bridge;
+use anchor_lang::solana_program::{self, instruction::Instruction};
+use anchor_spl::token_interface;
+use wormhole_anchor_sdk::{app, token_bridge};
 
 use crate::{
     context::RedeemNativeTransferWithPayload,
     error::BridgeError,
-    message::TokenMessage,
-    state::RedeemerConfig,
+    event::TransferRedeemed,
+    inbound::InboundTransfer,
+    message::{
+        RecipientKind, TransferCall, SEED_PREFIX_TRANSFER_CALL, TRANSFER_CALL_DISCRIMINATOR,
+    },
+    state::{ChainRelayerFee, PendingCall, RedeemerConfig, RegisteredToken, TokenRelayerFee},
+    token,
 };
 
-pub fn handler(
-    ctx: Context<RedeemNativeTransferWithPayload>,
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RedeemNativeTransferWithPayload<'info>>,
     _vaa_hash: [u8; 32],
+    unwrap: bool,
 ) -> Result<()> {
     require!(
-        ctx.accounts.token_bridge_claim.data_is_empty(),
+        !app::is_consumed(&ctx.accounts.token_bridge_claim),
         BridgeError::AlreadyRedeemed
     );
 
-    let TokenMessage::Hello { recipient } = ctx.accounts.vaa.message().data();
+    let mut registered_token = RegisteredToken::load(&ctx.accounts.registered_token)?;
     require!(
-        ctx.accounts.recipient.key().to_bytes() == *recipient,
-        BridgeError::InvalidRecipient
+        registered_token.inbound_enabled,
+        BridgeError::InboundTransfersDisabled
     );
 
+    let message = ctx.accounts.vaa.message().data();
+    require!(
+        !unwrap || ctx.accounts.mint.key() == anchor_spl::token::spl_token::native_mint::ID,
+        BridgeError::NotNativeMint
+    );
+    require!(
+        !unwrap || message.recipient_kind() != RecipientKind::Call,
+        BridgeError::InvalidRecipientKind
+    );
+    let recipient_is_ata = token::verify_recipient(
+        message.recipient_kind(),
+        message.recipient(),
+        &ctx.accounts.recipient,
+        &ctx.accounts.recipient_token_account,
+        &ctx.accounts.mint.key(),
+        &ctx.accounts.token_program.key(),
+    )?;
+
     let config_seeds = &[
         RedeemerConfig::SEED_PREFIX.as_ref(),
         &[ctx.accounts.config.bump],
@@ -49,32 +75,93 @@ pub fn handler(
         &[&config_seeds[..]],
     ))?;
 
-    let amount = token_bridge::denormalize_amount(
-        ctx.accounts.vaa.data().amount(),
-        ctx.accounts.mint.decimals,
-    );
+    // The tmp account holds the completed amount in the mint's decimals.
+    ctx.accounts.tmp_token_account.reload()?;
+    let amount = ctx.accounts.tmp_token_account.amount;
+    let decimals = ctx.accounts.mint.decimals;
+
+    // Transfers above the threshold or beyond the inbound cap are held in
+    // escrow until released; the relayer is paid either way.
+    let now = Clock::get()?.unix_timestamp;
+    let delayed = !registered_token.consume_inbound(amount, now);
+
+    // A missing recipient ATA is created at the payer's expense. Unwrapped
+    // SOL is paid out as lamports and needs none.
+    let recipient_ata_created = recipient_is_ata
+        && (!unwrap || delayed)
+        && token::create_ata_if_needed(
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.recipient_token_account.to_account_info(),
+            ctx.accounts.recipient.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.associated_token_program.to_account_info(),
+        )?;
 
     // Handle relayer fee if payer != recipient
-    if ctx.accounts.payer.key() != ctx.accounts.recipient.key() {
-        require!(
-            !ctx.accounts.payer_token_account.data_is_empty(),
-            BridgeError::NonExistentRelayerAta
+    let (relayer_amount, gas_dropoff) = if ctx.accounts.payer.key() != ctx.accounts.recipient.key()
+    {
+        let transfer = InboundTransfer {
+            amount,
+            decimals,
+            relayer_fee: ctx.accounts.config.resolve_relayer_amount(
+                amount,
+                ChainRelayerFee::load(&ctx.accounts.chain_relayer_fee)?.as_ref(),
+                TokenRelayerFee::load(&ctx.accounts.token_relayer_fee)?.as_ref(),
+            ),
+            max_relayer_fee: message.max_relayer_fee(),
+            gas_dropoff: message.gas_dropoff(),
+        };
+        // The relayer recoups the rent of a recipient ATA it created and the
+        // SOL it drops off to the recipient, in tokens at the owner-set
+        // exchange rate.
+        let ata_rent = if recipient_ata_created {
+            ctx.accounts.recipient_token_account.lamports()
+        } else {
+            0
+        };
+        let (relayer_amount, gas_dropoff) = transfer.relayer_payout(
+            &registered_token,
+            ata_rent,
+            ctx.accounts.recipient.lamports(),
+            Rent::get()?.minimum_balance(0),
         );
-
-        let relayer_amount = ctx.accounts.config.compute_relayer_amount(amount);
+        if gas_dropoff > 0 {
+            solana_program::program::invoke(
+                &solana_program::system_instruction::transfer(
+                    &ctx.accounts.payer.key(),
+                    &ctx.accounts.recipient.key(),
+                    gas_dropoff,
+                ),
+                &[
+                    ctx.accounts.payer.to_account_info(),
+                    ctx.accounts.recipient.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+            )?;
+        }
 
         if relayer_amount > 0 {
-            anchor_spl::token::transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    anchor_spl::token::Transfer {
-                        from: ctx.accounts.tmp_token_account.to_account_info(),
-                        to: ctx.accounts.payer_token_account.to_account_info(),
-                        authority: ctx.accounts.config.to_account_info(),
-                    },
-                    &[&config_seeds[..]],
-                ),
+            token::create_ata_if_needed(
+                ctx.accounts.payer.to_account_info(),
+                ctx.accounts.payer_token_account.to_account_info(),
+                ctx.accounts.payer.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.associated_token_program.to_account_info(),
+            )?;
+
+            token::transfer_checked(
+                &ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.tmp_token_account.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                ctx.accounts.payer_token_account.to_account_info(),
+                ctx.accounts.config.to_account_info(),
                 relayer_amount,
+                decimals,
+                &[&config_seeds[..]],
             )?;
         }
 
@@ -83,36 +170,171 @@ pub fn handler(
             ctx.accounts.payer.key()
         );
 
-        anchor_spl::token::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                anchor_spl::token::Transfer {
-                    from: ctx.accounts.tmp_token_account.to_account_info(),
-                    to: ctx.accounts.recipient_token_account.to_account_info(),
-                    authority: ctx.accounts.config.to_account_info(),
-                },
-                &[&config_seeds[..]],
-            ),
-            amount - relayer_amount,
-        )?;
+        (relayer_amount, gas_dropoff)
     } else {
-        anchor_spl::token::transfer(
-            CpiContext::new_with_signer(
+        (0, 0)
+    };
+
+    // A call recipient's program is called once the tokens are paid out, or
+    // when they are released if the transfer is delayed.
+    let call = (message.recipient_kind() == RecipientKind::Call).then(|| PendingCall {
+        program: Pubkey::from(*message.recipient()),
+        call: TransferCall {
+            amount: amount - relayer_amount,
+            source_chain: ctx.accounts.vaa.emitter_chain(),
+            sender: message.sender(),
+            data: message.app_data().to_vec(),
+        },
+    });
+
+    if !delayed {
+        require!(
+            ctx.accounts.pending_release.is_none() && ctx.accounts.escrow_token_account.is_none(),
+            BridgeError::UnexpectedPendingRelease
+        );
+
+        if unwrap {
+            // Closing the wSOL tmp account into the config hands over its
+            // lamports, which are then split between the recipient and the
+            // payer who funded the account's rent.
+            let tmp_lamports = ctx.accounts.tmp_token_account.to_account_info().lamports();
+            token_interface::close_account(CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
-                anchor_spl::token::Transfer {
-                    from: ctx.accounts.tmp_token_account.to_account_info(),
-                    to: ctx.accounts.recipient_token_account.to_account_info(),
+                token_interface::CloseAccount {
+                    account: ctx.accounts.tmp_token_account.to_account_info(),
+                    destination: ctx.accounts.config.to_account_info(),
                     authority: ctx.accounts.config.to_account_info(),
                 },
                 &[&config_seeds[..]],
-            ),
-            amount,
+            ))?;
+
+            ctx.accounts.config.sub_lamports(tmp_lamports)?;
+            ctx.accounts
+                .recipient
+                .add_lamports(amount - relayer_amount)?;
+            ctx.accounts
+                .payer
+                .add_lamports(tmp_lamports - (amount - relayer_amount))?;
+
+            msg!(
+                "RedeemNativeTransferWithPayload :: unwrapped {} lamports",
+                amount - relayer_amount
+            );
+        } else {
+            token::transfer_checked(
+                &ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.tmp_token_account.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                ctx.accounts.recipient_token_account.to_account_info(),
+                ctx.accounts.config.to_account_info(),
+                amount - relayer_amount,
+                decimals,
+                &[&config_seeds[..]],
+            )?;
+        }
+
+        if let Some(PendingCall { program, call }) = call {
+            call_recipient(
+                ctx.accounts.recipient_program.as_ref(),
+                &program,
+                ctx.accounts.transfer_call_signer.as_ref(),
+                &[
+                    ctx.accounts.recipient.to_account_info(),
+                    ctx.accounts.recipient_token_account.to_account_info(),
+                    ctx.accounts.mint.to_account_info(),
+                ],
+                ctx.remaining_accounts,
+                call,
+            )?;
+        }
+    } else {
+        let release_delay = ctx
+            .accounts
+            .governor_config
+            .as_ref()
+            .ok_or(BridgeError::GovernorNotConfigured)?
+            .release_delay;
+        let (Some(pending_release), Some(escrow_token_account)) = (
+            &mut ctx.accounts.pending_release,
+            &ctx.accounts.escrow_token_account,
+        ) else {
+            return err!(BridgeError::PendingReleaseRequired);
+        };
+
+        token::transfer_checked(
+            &ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.tmp_token_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            escrow_token_account.to_account_info(),
+            ctx.accounts.config.to_account_info(),
+            amount - relayer_amount,
+            decimals,
+            &[&config_seeds[..]],
         )?;
+
+        pending_release.mint = registered_token.mint;
+        pending_release.recipient = ctx.accounts.recipient.key();
+        pending_release.recipient_token_account = ctx.accounts.recipient_token_account.key();
+        pending_release.amount = amount - relayer_amount;
+        pending_release.payer = ctx.accounts.payer.key();
+        pending_release.release_time = now + release_delay;
+        pending_release.held = false;
+        pending_release.call = call;
+        pending_release.emitter_chain = ctx.accounts.vaa.emitter_chain();
+        pending_release.sequence = ctx.accounts.vaa.sequence();
+        pending_release.refund_recipient =
+            message.refund_recipient(ctx.accounts.vaa.data().from_address());
+
+        msg!(
+            "RedeemNativeTransferWithPayload :: {} delayed until {}",
+            pending_release.amount,
+            pending_release.release_time
+        );
     }
 
-    anchor_spl::token::close_account(CpiContext::new_with_signer(
+    registered_token.save(&ctx.accounts.registered_token)?;
+
+    let vaa = &ctx.accounts.vaa;
+    let receipt = &mut ctx.accounts.receipt;
+    receipt.emitter_chain = vaa.emitter_chain();
+    receipt.sequence = vaa.sequence();
+    receipt.mint = registered_token.mint;
+    receipt.recipient = ctx.accounts.recipient.key();
+    receipt.relayer = ctx.accounts.payer.key();
+    receipt.amount = amount;
+    receipt.relayer_fee = relayer_amount;
+    receipt.decimals = decimals;
+    receipt.timestamp = now;
+    receipt.sender = message.sender();
+    receipt.memo = message.memo();
+    receipt.app_data = message.app_data().to_vec();
+
+    if let Some(transfer_receipt) = &mut ctx.accounts.refunded_transfer_receipt {
+        transfer_receipt.mark_refunded(message, vaa.emitter_chain(), &registered_token.mint)?;
+    }
+
+    emit!(TransferRedeemed {
+        emitter_chain: vaa.emitter_chain(),
+        sequence: vaa.sequence(),
+        mint: registered_token.mint,
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+        relayer_amount,
+        gas_dropoff,
+        delayed,
+        sender: message.sender(),
+        memo: message.memo(),
+        app_data: message.app_data().to_vec(),
+    });
+
+    // An unwrapped tmp account was already closed above.
+    if unwrap && !delayed {
+        return Ok(());
+    }
+
+    token_interface::close_account(CpiContext::new_with_signer(
         ctx.accounts.token_program.to_account_info(),
-        anchor_spl::token::CloseAccount {
+        token_interface::CloseAccount {
             account: ctx.accounts.tmp_token_account.to_account_info(),
             destination: ctx.accounts.payer.to_account_info(),
             authority: ctx.accounts.config.to_account_info(),
@@ -120,3 +342,61 @@ pub fn handler(
         &[&config_seeds[..]],
     ))
 }
+
+/// Call the program of a `Call` recipient once its tokens are paid out,
+/// signed by the transfer call PDA so it can tell the call came from the
+/// bridge. The program receives that PDA, `accounts` (recipient, recipient
+/// token account and mint) and then the remaining accounts.
+pub(crate) fn call_recipient<'info>(
+    recipient_program: Option<&UncheckedAccount<'info>>,
+    program_id: &Pubkey,
+    transfer_call_signer: Option<&UncheckedAccount<'info>>,
+    accounts: &[AccountInfo<'info>],
+    remaining_accounts: &[AccountInfo<'info>],
+    call: TransferCall,
+) -> Result<()> {
+    let recipient_program = recipient_program
+        .filter(|program| program.key() == *program_id && program.executable)
+        .ok_or(BridgeError::InvalidRecipientProgram)?;
+
+    let (signer_key, signer_bump) =
+        Pubkey::find_program_address(&[SEED_PREFIX_TRANSFER_CALL], &crate::ID);
+    let signer = transfer_call_signer
+        .filter(|signer| signer.key() == signer_key)
+        .ok_or(BridgeError::InvalidTransferCallSigner)?;
+
+    let mut metas = vec![AccountMeta::new_readonly(signer_key, true)];
+    metas.extend(accounts.iter().chain(remaining_accounts).map(|info| {
+        if info.is_writable {
+            AccountMeta::new(info.key(), false)
+        } else {
+            AccountMeta::new_readonly(info.key(), false)
+        }
+    }));
+
+    let mut account_infos = vec![signer.to_account_info()];
+    account_infos.extend_from_slice(accounts);
+    account_infos.extend_from_slice(remaining_accounts);
+    account_infos.push(recipient_program.to_account_info());
+
+    let mut data = TRANSFER_CALL_DISCRIMINATOR.to_vec();
+    call.serialize(&mut data)?;
+
+    solana_program::program::invoke_signed(
+        &Instruction {
+            program_id: recipient_program.key(),
+            accounts: metas,
+            data,
+        },
+        &account_infos,
+        &[&[SEED_PREFIX_TRANSFER_CALL, &[signer_bump]]],
+    )?;
+
+    msg!(
+        "Transfer of {} passed to recipient program {}",
+        call.amount,
+        recipient_program.key()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use wormhole_anchor_sdk::{app, token_bridge};
+
+use crate::{
+    context::RefundWrappedTransfer,
+    error::BridgeError,
+    event::TransferRefunded,
+    message::TokenMessage,
+    state::{RedeemerConfig, SenderConfig},
+};
+
+pub fn handler(ctx: Context<RefundWrappedTransfer>, _vaa_hash: [u8; 32]) -> Result<()> {
+    require!(
+        !app::is_consumed(&ctx.accounts.token_bridge_claim),
+        BridgeError::AlreadyRedeemed
+    );
+
+    let vaa = &ctx.accounts.vaa;
+    let message = vaa.message().data();
+    let refund_recipient = message.refund_recipient(vaa.data().from_address());
+    require!(refund_recipient != [0; 32], BridgeError::NotRefundable);
+    require!(
+        message.has_refund_address()
+            || ctx
+                .accounts
+                .refund_config
+                .is_refundable(vaa.timestamp(), ctx.accounts.clock.unix_timestamp),
+        BridgeError::RefundNotDue
+    );
+
+    let redeemer_seeds = &[
+        RedeemerConfig::SEED_PREFIX.as_ref(),
+        &[ctx.accounts.redeemer_config.bump],
+    ];
+    let sender_seeds = &[
+        SenderConfig::SEED_PREFIX.as_ref(),
+        &[ctx.accounts.sender_config.bump],
+    ];
+
+    token_bridge::complete_transfer_wrapped_with_payload(CpiContext::new_with_signer(
+        ctx.accounts.token_bridge_program.to_account_info(),
+        token_bridge::CompleteTransferWrappedWithPayload {
+            payer: ctx.accounts.payer.to_account_info(),
+            config: ctx.accounts.token_bridge_config.to_account_info(),
+            vaa: ctx.accounts.vaa.to_account_info(),
+            claim: ctx.accounts.token_bridge_claim.to_account_info(),
+            foreign_endpoint: ctx.accounts.token_bridge_foreign_endpoint.to_account_info(),
+            to: ctx.accounts.tmp_token_account.to_account_info(),
+            redeemer: ctx.accounts.redeemer_config.to_account_info(),
+            wrapped_mint: ctx.accounts.token_bridge_wrapped_mint.to_account_info(),
+            wrapped_metadata: ctx.accounts.token_bridge_wrapped_meta.to_account_info(),
+            mint_authority: ctx.accounts.token_bridge_mint_authority.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            wormhole_program: ctx.accounts.wormhole_program.to_account_info(),
+        },
+        &[&redeemer_seeds[..]],
+    ))?;
+
+    let amount = vaa.data().amount();
+
+    anchor_spl::token::approve(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::Approve {
+                to: ctx.accounts.tmp_token_account.to_account_info(),
+                delegate: ctx.accounts.token_bridge_authority_signer.to_account_info(),
+                authority: ctx.accounts.redeemer_config.to_account_info(),
+            },
+            &[&redeemer_seeds[..]],
+        ),
+        amount,
+    )?;
+
+    let payload = TokenMessage::Refund {
+        recipient: refund_recipient,
+        sequence: vaa.sequence(),
+    }
+    .try_to_vec()?;
+
+    token_bridge::transfer_wrapped_with_payload(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_bridge_program.to_account_info(),
+            token_bridge::TransferWrappedWithPayload {
+                payer: ctx.accounts.payer.to_account_info(),
+                config: ctx.accounts.token_bridge_config.to_account_info(),
+                from: ctx.accounts.tmp_token_account.to_account_info(),
+                from_owner: ctx.accounts.redeemer_config.to_account_info(),
+                wrapped_mint: ctx.accounts.token_bridge_wrapped_mint.to_account_info(),
+                wrapped_metadata: ctx.accounts.token_bridge_wrapped_meta.to_account_info(),
+                authority_signer: ctx.accounts.token_bridge_authority_signer.to_account_info(),
+                wormhole_bridge: ctx.accounts.wormhole_bridge.to_account_info(),
+                wormhole_message: ctx.accounts.wormhole_message.to_account_info(),
+                wormhole_emitter: ctx.accounts.token_bridge_emitter.to_account_info(),
+                wormhole_sequence: ctx.accounts.token_bridge_sequence.to_account_info(),
+                wormhole_fee_collector: ctx.accounts.wormhole_fee_collector.to_account_info(),
+                clock: ctx.accounts.clock.to_account_info(),
+                sender: ctx.accounts.sender_config.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                wormhole_program: ctx.accounts.wormhole_program.to_account_info(),
+            },
+            &[&sender_seeds[..], &redeemer_seeds[..]],
+        ),
+        0,
+        amount,
+        ctx.accounts.foreign_contract.peer.address,
+        vaa.emitter_chain(),
+        payload,
+        &ctx.program_id.key(),
+    )?;
+
+    emit!(TransferRefunded {
+        emitter_chain: vaa.emitter_chain(),
+        sequence: vaa.sequence(),
+        mint: ctx.accounts.token_bridge_wrapped_mint.key(),
+        amount,
+        refund_recipient,
+    });
+
+    anchor_spl::token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        anchor_spl::token::CloseAccount {
+            account: ctx.accounts.tmp_token_account.to_account_info(),
+            destination: ctx.accounts.payer.to_account_info(),
+            authority: ctx.accounts.redeemer_config.to_account_info(),
+        },
+        &[&redeemer_seeds[..]],
+    ))
+}
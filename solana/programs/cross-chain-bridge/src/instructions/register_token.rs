@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::context::RegisterToken;
+
+pub fn handler(
+    ctx: Context<RegisterToken>,
+    outbound_enabled: bool,
+    inbound_enabled: bool,
+) -> Result<()> {
+    let registered_token = &mut ctx.accounts.registered_token;
+    registered_token.mint = ctx.accounts.mint.key();
+    registered_token.outbound_enabled = outbound_enabled;
+    registered_token.inbound_enabled = inbound_enabled;
+
+    msg!(
+        "Token registered: mint={}, outbound={}, inbound={}",
+        registered_token.mint,
+        outbound_enabled,
+        inbound_enabled
+    );
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+
+use crate::{
+    context::ReleasePendingTransfer,
+    instructions::redeem_native_transfer::call_recipient,
+    state::{PendingCall, RedeemerConfig},
+    token,
+};
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReleasePendingTransfer<'info>>,
+    _vaa_hash: [u8; 32],
+) -> Result<()> {
+    let pending_release = &ctx.accounts.pending_release;
+    let now = Clock::get()?.unix_timestamp;
+    pending_release.check_release(now)?;
+
+    // Delayed transfers skipped the inbound cap when redeemed.
+    ctx.accounts
+        .registered_token
+        .release_inbound(pending_release.amount, now);
+
+    let config_seeds = &[
+        RedeemerConfig::SEED_PREFIX.as_ref(),
+        &[ctx.accounts.config.bump],
+    ];
+
+    token::transfer_checked(
+        &ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_token_account.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.recipient_token_account.to_account_info(),
+        ctx.accounts.config.to_account_info(),
+        ctx.accounts.escrow_token_account.amount,
+        ctx.accounts.mint.decimals,
+        &[&config_seeds[..]],
+    )?;
+
+    if let Some(PendingCall { program, call }) = pending_release.call.clone() {
+        call_recipient(
+            ctx.accounts.recipient_program.as_ref(),
+            &program,
+            ctx.accounts.transfer_call_signer.as_ref(),
+            &[
+                ctx.accounts.recipient.to_account_info(),
+                ctx.accounts.recipient_token_account.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+            ],
+            ctx.remaining_accounts,
+            call,
+        )?;
+    }
+
+    msg!(
+        "Pending transfer released: {} to {}",
+        pending_release.amount,
+        pending_release.recipient
+    );
+
+    token_interface::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token_interface::CloseAccount {
+            account: ctx.accounts.escrow_token_account.to_account_info(),
+            destination: ctx.accounts.rent_payer.to_account_info(),
+            authority: ctx.accounts.config.to_account_info(),
+        },
+        &[&config_seeds[..]],
+    ))
+}
@@ -1,24 +1,31 @@
 use anchor_lang::prelude::*;
-use wormhole_anchor_sdk::{token_bridge, wormhole};
+use anchor_spl::token_interface;
+use wormhole_anchor_sdk::token_bridge;
 
 use crate::{
-    context::{SendNativeTokensWithPayload, SEED_PREFIX_BRIDGED},
-    error::BridgeError,
-    message::TokenMessage,
-    state::SenderConfig,
+    context::SendNativeTokensWithPayload, error::BridgeError, message::TransferMode,
+    outbound::OutboundTransfer, state::SenderConfig, token,
 };
 
-pub fn handler(
-    ctx: Context<SendNativeTokensWithPayload>,
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SendNativeTokensWithPayload<'info>>,
     batch_id: u32,
     amount: u64,
     recipient_address: [u8; 32],
     recipient_chain: u16,
+    mode: TransferMode,
+    require_exact: bool,
 ) -> Result<()> {
-    // Token Bridge truncates amounts to 8 decimals
+    // Token Bridge truncates amounts to 8 decimals, so only the truncated
+    // amount is taken from the sender, unless an exact amount is required.
     let truncated_amount = token_bridge::truncate_amount(amount, ctx.accounts.mint.decimals);
     require!(truncated_amount > 0, BridgeError::ZeroBridgeAmount);
+    require!(
+        !require_exact || truncated_amount == amount,
+        BridgeError::InexactAmount
+    );
 
+    // The dust is never taken from the sender.
     if truncated_amount != amount {
         msg!(
             "SendNativeTokensWithPayload :: truncating amount {} to {}",
@@ -27,34 +34,45 @@ pub fn handler(
         );
     }
 
-    require!(
-        recipient_chain > 0
-            && recipient_chain != wormhole::CHAIN_ID_SOLANA
-            && !recipient_address.iter().all(|&x| x == 0),
-        BridgeError::InvalidRecipient,
-    );
-
     let config_seeds = &[
         SenderConfig::SEED_PREFIX.as_ref(),
         &[ctx.accounts.config.bump],
     ];
+    let decimals = ctx.accounts.mint.decimals;
 
-    anchor_spl::token::transfer(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            anchor_spl::token::Transfer {
-                from: ctx.accounts.from_token_account.to_account_info(),
-                to: ctx.accounts.tmp_token_account.to_account_info(),
-                authority: ctx.accounts.payer.to_account_info(),
-            },
-        ),
+    token::transfer_checked(
+        &ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.from_token_account.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.tmp_token_account.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
         truncated_amount,
+        decimals,
+        &[],
+    )?;
+
+    let transfer = OutboundTransfer {
+        sender: ctx.accounts.payer.key(),
+        mint: ctx.accounts.mint.key(),
+        decimals,
+        amount: truncated_amount,
+        recipient_chain,
+        recipient_address,
+        mode,
+    };
+    transfer.verify()?;
+
+    let now = ctx.accounts.clock.unix_timestamp;
+    transfer.consume_limits(
+        &ctx.accounts.registered_token,
+        &ctx.accounts.outbound_chain_limit,
+        now,
     )?;
 
-    anchor_spl::token::approve(
+    token_interface::approve(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
-            anchor_spl::token::Approve {
+            token_interface::Approve {
                 to: ctx.accounts.tmp_token_account.to_account_info(),
                 delegate: ctx.accounts.token_bridge_authority_signer.to_account_info(),
                 authority: ctx.accounts.config.to_account_info(),
@@ -64,57 +82,42 @@ pub fn handler(
         truncated_amount,
     )?;
 
-    let payload = TokenMessage::Hello {
-        recipient: recipient_address,
-    }
-    .try_to_vec()?;
+    transfer.record(
+        &mut ctx.accounts.transfer_receipt,
+        ctx.accounts.token_bridge_sequence.value(),
+        now,
+    );
 
-    token_bridge::transfer_native_with_payload(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_bridge_program.to_account_info(),
-            token_bridge::TransferNativeWithPayload {
-                payer: ctx.accounts.payer.to_account_info(),
-                config: ctx.accounts.token_bridge_config.to_account_info(),
-                from: ctx.accounts.tmp_token_account.to_account_info(),
-                mint: ctx.accounts.mint.to_account_info(),
-                custody: ctx.accounts.token_bridge_custody.to_account_info(),
-                authority_signer: ctx.accounts.token_bridge_authority_signer.to_account_info(),
-                custody_signer: ctx.accounts.token_bridge_custody_signer.to_account_info(),
-                wormhole_bridge: ctx.accounts.wormhole_bridge.to_account_info(),
-                wormhole_message: ctx.accounts.wormhole_message.to_account_info(),
-                wormhole_emitter: ctx.accounts.token_bridge_emitter.to_account_info(),
-                wormhole_sequence: ctx.accounts.token_bridge_sequence.to_account_info(),
-                wormhole_fee_collector: ctx.accounts.wormhole_fee_collector.to_account_info(),
-                clock: ctx.accounts.clock.to_account_info(),
-                sender: ctx.accounts.config.to_account_info(),
-                rent: ctx.accounts.rent.to_account_info(),
-                system_program: ctx.accounts.system_program.to_account_info(),
-                token_program: ctx.accounts.token_program.to_account_info(),
-                wormhole_program: ctx.accounts.wormhole_program.to_account_info(),
-            },
-            &[
-                &config_seeds[..],
-                &[
-                    SEED_PREFIX_BRIDGED,
-                    &ctx.accounts
-                        .token_bridge_sequence
-                        .next_value()
-                        .to_le_bytes()[..],
-                    &[ctx.bumps.wormhole_message],
-                ],
-            ],
-        ),
+    transfer.bridge_native(
+        ctx.accounts.token_bridge_program.to_account_info(),
+        token_bridge::TransferNative {
+            payer: ctx.accounts.payer.to_account_info(),
+            config: ctx.accounts.token_bridge_config.to_account_info(),
+            from: ctx.accounts.tmp_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            custody: ctx.accounts.token_bridge_custody.to_account_info(),
+            authority_signer: ctx.accounts.token_bridge_authority_signer.to_account_info(),
+            custody_signer: ctx.accounts.token_bridge_custody_signer.to_account_info(),
+            wormhole_bridge: ctx.accounts.wormhole_bridge.to_account_info(),
+            wormhole_message: ctx.accounts.wormhole_message.to_account_info(),
+            wormhole_emitter: ctx.accounts.token_bridge_emitter.to_account_info(),
+            wormhole_sequence: ctx.accounts.token_bridge_sequence.to_account_info(),
+            wormhole_fee_collector: ctx.accounts.wormhole_fee_collector.to_account_info(),
+            clock: ctx.accounts.clock.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            wormhole_program: ctx.accounts.wormhole_program.to_account_info(),
+        },
+        ctx.accounts.config.to_account_info(),
         batch_id,
-        truncated_amount,
-        ctx.accounts.foreign_contract.address,
-        recipient_chain,
-        payload,
-        &ctx.program_id.key(),
+        ctx.accounts.foreign_contract.peer.address,
+        &[&config_seeds[..]],
     )?;
 
-    anchor_spl::token::close_account(CpiContext::new_with_signer(
+    token_interface::close_account(CpiContext::new_with_signer(
         ctx.accounts.token_program.to_account_info(),
-        anchor_spl::token::CloseAccount {
+        token_interface::CloseAccount {
             account: ctx.accounts.tmp_token_account.to_account_info(),
             destination: ctx.accounts.payer.to_account_info(),
             authority: ctx.accounts.config.to_account_info(),
@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program;
+use wormhole_anchor_sdk::token_bridge;
+
+use crate::{
+    context::SendSolWithPayload,
+    error::BridgeError,
+    message::{TransferMode, TransferOptions},
+    outbound::OutboundTransfer,
+    state::SenderConfig,
+};
+
+pub fn handler(
+    ctx: Context<SendSolWithPayload>,
+    batch_id: u32,
+    amount: u64,
+    recipient_address: [u8; 32],
+    recipient_chain: u16,
+    options: Option<TransferOptions>,
+    require_exact: bool,
+) -> Result<()> {
+    // Token Bridge truncates amounts to 8 decimals, so only the truncated
+    // lamports are wrapped.
+    let truncated_amount = token_bridge::truncate_amount(amount, ctx.accounts.mint.decimals);
+    require!(truncated_amount > 0, BridgeError::ZeroBridgeAmount);
+    require!(
+        !require_exact || truncated_amount == amount,
+        BridgeError::InexactAmount
+    );
+
+    // The dust is never taken from the payer.
+    if truncated_amount != amount {
+        msg!(
+            "SendSolWithPayload :: truncating amount {} to {}",
+            amount,
+            truncated_amount
+        );
+    }
+
+    let transfer = OutboundTransfer {
+        sender: ctx.accounts.payer.key(),
+        mint: ctx.accounts.mint.key(),
+        decimals: ctx.accounts.mint.decimals,
+        amount: truncated_amount,
+        recipient_chain,
+        recipient_address,
+        mode: TransferMode::WithPayload(options),
+    };
+    transfer.verify()?;
+
+    let now = ctx.accounts.clock.unix_timestamp;
+    transfer.consume_limits(
+        &ctx.accounts.registered_token,
+        &ctx.accounts.outbound_chain_limit,
+        now,
+    )?;
+
+    let config_seeds = &[
+        SenderConfig::SEED_PREFIX.as_ref(),
+        &[ctx.accounts.config.bump],
+    ];
+
+    // Wrap the lamports in the tmp account.
+    solana_program::program::invoke(
+        &solana_program::system_instruction::transfer(
+            &ctx.accounts.payer.key(),
+            &ctx.accounts.tmp_token_account.key(),
+            truncated_amount,
+        ),
+        &[
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.tmp_token_account.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    anchor_spl::token::sync_native(CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        anchor_spl::token::SyncNative {
+            account: ctx.accounts.tmp_token_account.to_account_info(),
+        },
+    ))?;
+
+    anchor_spl::token::approve(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::Approve {
+                to: ctx.accounts.tmp_token_account.to_account_info(),
+                delegate: ctx.accounts.token_bridge_authority_signer.to_account_info(),
+                authority: ctx.accounts.config.to_account_info(),
+            },
+            &[&config_seeds[..]],
+        ),
+        truncated_amount,
+    )?;
+
+    transfer.record(
+        &mut ctx.accounts.transfer_receipt,
+        ctx.accounts.token_bridge_sequence.value(),
+        now,
+    );
+
+    transfer.bridge_native(
+        ctx.accounts.token_bridge_program.to_account_info(),
+        token_bridge::TransferNative {
+            payer: ctx.accounts.payer.to_account_info(),
+            config: ctx.accounts.token_bridge_config.to_account_info(),
+            from: ctx.accounts.tmp_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            custody: ctx.accounts.token_bridge_custody.to_account_info(),
+            authority_signer: ctx.accounts.token_bridge_authority_signer.to_account_info(),
+            custody_signer: ctx.accounts.token_bridge_custody_signer.to_account_info(),
+            wormhole_bridge: ctx.accounts.wormhole_bridge.to_account_info(),
+            wormhole_message: ctx.accounts.wormhole_message.to_account_info(),
+            wormhole_emitter: ctx.accounts.token_bridge_emitter.to_account_info(),
+            wormhole_sequence: ctx.accounts.token_bridge_sequence.to_account_info(),
+            wormhole_fee_collector: ctx.accounts.wormhole_fee_collector.to_account_info(),
+            clock: ctx.accounts.clock.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            wormhole_program: ctx.accounts.wormhole_program.to_account_info(),
+        },
+        ctx.accounts.config.to_account_info(),
+        batch_id,
+        ctx.accounts.foreign_contract.peer.address,
+        &[&config_seeds[..]],
+    )?;
+
+    anchor_spl::token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        anchor_spl::token::CloseAccount {
+            account: ctx.accounts.tmp_token_account.to_account_info(),
+            destination: ctx.accounts.payer.to_account_info(),
+            authority: ctx.accounts.config.to_account_info(),
+        },
+        &[&config_seeds[..]],
+    ))
+}
@@ -1,10 +1,8 @@
 use anchor_lang::prelude::*;
-use wormhole_anchor_sdk::{token_bridge, wormhole};
+use wormhole_anchor_sdk::token_bridge;
 
 use crate::{
-    context::{SendWrappedTokensWithPayload, SEED_PREFIX_BRIDGED},
-    error::BridgeError,
-    message::TokenMessage,
+    context::SendWrappedTokensWithPayload, message::TransferMode, outbound::OutboundTransfer,
     state::SenderConfig,
 };
 
@@ -14,14 +12,25 @@ pub fn handler(
     amount: u64,
     recipient_address: [u8; 32],
     recipient_chain: u16,
+    mode: TransferMode,
 ) -> Result<()> {
-    require!(amount > 0, BridgeError::ZeroBridgeAmount);
-    require!(
-        recipient_chain > 0
-            && recipient_chain != wormhole::CHAIN_ID_SOLANA
-            && !recipient_address.iter().all(|&x| x == 0),
-        BridgeError::InvalidRecipient,
-    );
+    let transfer = OutboundTransfer {
+        sender: ctx.accounts.payer.key(),
+        mint: ctx.accounts.token_bridge_wrapped_mint.key(),
+        decimals: ctx.accounts.token_bridge_wrapped_mint.decimals,
+        amount,
+        recipient_chain,
+        recipient_address,
+        mode,
+    };
+    transfer.verify()?;
+
+    let now = ctx.accounts.clock.unix_timestamp;
+    transfer.consume_limits(
+        &ctx.accounts.registered_token,
+        &ctx.accounts.outbound_chain_limit,
+        now,
+    )?;
 
     let config_seeds = &[
         SenderConfig::SEED_PREFIX.as_ref(),
@@ -53,52 +62,37 @@ pub fn handler(
         amount,
     )?;
 
-    let payload = TokenMessage::Hello {
-        recipient: recipient_address,
-    }
-    .try_to_vec()?;
+    transfer.record(
+        &mut ctx.accounts.transfer_receipt,
+        ctx.accounts.token_bridge_sequence.value(),
+        now,
+    );
 
-    token_bridge::transfer_wrapped_with_payload(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_bridge_program.to_account_info(),
-            token_bridge::TransferWrappedWithPayload {
-                payer: ctx.accounts.payer.to_account_info(),
-                config: ctx.accounts.token_bridge_config.to_account_info(),
-                from: ctx.accounts.tmp_token_account.to_account_info(),
-                from_owner: ctx.accounts.config.to_account_info(),
-                wrapped_mint: ctx.accounts.token_bridge_wrapped_mint.to_account_info(),
-                wrapped_metadata: ctx.accounts.token_bridge_wrapped_meta.to_account_info(),
-                authority_signer: ctx.accounts.token_bridge_authority_signer.to_account_info(),
-                wormhole_bridge: ctx.accounts.wormhole_bridge.to_account_info(),
-                wormhole_message: ctx.accounts.wormhole_message.to_account_info(),
-                wormhole_emitter: ctx.accounts.token_bridge_emitter.to_account_info(),
-                wormhole_sequence: ctx.accounts.token_bridge_sequence.to_account_info(),
-                wormhole_fee_collector: ctx.accounts.wormhole_fee_collector.to_account_info(),
-                clock: ctx.accounts.clock.to_account_info(),
-                sender: ctx.accounts.config.to_account_info(),
-                rent: ctx.accounts.rent.to_account_info(),
-                system_program: ctx.accounts.system_program.to_account_info(),
-                token_program: ctx.accounts.token_program.to_account_info(),
-                wormhole_program: ctx.accounts.wormhole_program.to_account_info(),
-            },
-            &[
-                &config_seeds[..],
-                &[
-                    SEED_PREFIX_BRIDGED,
-                    &ctx.accounts
-                        .token_bridge_sequence
-                        .next_value()
-                        .to_le_bytes()[..],
-                    &[ctx.bumps.wormhole_message],
-                ],
-            ],
-        ),
+    transfer.bridge_wrapped(
+        ctx.accounts.token_bridge_program.to_account_info(),
+        token_bridge::TransferWrapped {
+            payer: ctx.accounts.payer.to_account_info(),
+            config: ctx.accounts.token_bridge_config.to_account_info(),
+            from: ctx.accounts.tmp_token_account.to_account_info(),
+            from_owner: ctx.accounts.config.to_account_info(),
+            wrapped_mint: ctx.accounts.token_bridge_wrapped_mint.to_account_info(),
+            wrapped_metadata: ctx.accounts.token_bridge_wrapped_meta.to_account_info(),
+            authority_signer: ctx.accounts.token_bridge_authority_signer.to_account_info(),
+            wormhole_bridge: ctx.accounts.wormhole_bridge.to_account_info(),
+            wormhole_message: ctx.accounts.wormhole_message.to_account_info(),
+            wormhole_emitter: ctx.accounts.token_bridge_emitter.to_account_info(),
+            wormhole_sequence: ctx.accounts.token_bridge_sequence.to_account_info(),
+            wormhole_fee_collector: ctx.accounts.wormhole_fee_collector.to_account_info(),
+            clock: ctx.accounts.clock.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            wormhole_program: ctx.accounts.wormhole_program.to_account_info(),
+        },
+        ctx.accounts.config.to_account_info(),
         batch_id,
-        amount,
-        ctx.accounts.foreign_contract.address,
-        recipient_chain,
-        payload,
-        &ctx.program_id.key(),
+        ctx.accounts.foreign_contract.peer.address,
+        &[&config_seeds[..]],
     )?;
 
     anchor_spl::token::close_account(CpiContext::new_with_signer(
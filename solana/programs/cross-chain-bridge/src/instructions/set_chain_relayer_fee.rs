@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::{context::SetChainRelayerFee, error::BridgeError};
+
+pub fn handler(
+    ctx: Context<SetChainRelayerFee>,
+    chain: u16,
+    relayer_fee: u32,
+    relayer_fee_precision: u32,
+) -> Result<()> {
+    require!(
+        relayer_fee < relayer_fee_precision,
+        BridgeError::InvalidRelayerFee,
+    );
+
+    let chain_relayer_fee = &mut ctx.accounts.chain_relayer_fee;
+    chain_relayer_fee.chain = chain;
+    chain_relayer_fee.relayer_fee = relayer_fee;
+    chain_relayer_fee.relayer_fee_precision = relayer_fee_precision;
+
+    msg!(
+        "Chain relayer fee updated: chain={}, fee={}/{}",
+        chain,
+        relayer_fee,
+        relayer_fee_precision
+    );
+
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::{context::SetGovernor, error::BridgeError};
+
+pub fn handler(ctx: Context<SetGovernor>, guardian: Pubkey, release_delay: i64) -> Result<()> {
+    require!(release_delay >= 0, BridgeError::InvalidReleaseDelay);
+
+    let governor_config = &mut ctx.accounts.governor_config;
+    governor_config.guardian = guardian;
+    governor_config.release_delay = release_delay;
+
+    msg!(
+        "Governor updated: guardian={}, release_delay={}",
+        guardian,
+        release_delay
+    );
+
+    Ok(())
+}
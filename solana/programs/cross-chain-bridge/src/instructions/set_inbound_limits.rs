@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::context::SetInboundLimits;
+
+pub fn handler(
+    ctx: Context<SetInboundLimits>,
+    inbound_threshold: u64,
+    inbound_limit: u64,
+) -> Result<()> {
+    let registered_token = &mut ctx.accounts.registered_token;
+    registered_token.inbound_threshold = inbound_threshold;
+    registered_token
+        .inbound_limit
+        .set_limit(inbound_limit, Clock::get()?.unix_timestamp);
+
+    msg!(
+        "Inbound limits updated: mint={}, threshold={}, inbound_limit={}",
+        registered_token.mint,
+        inbound_threshold,
+        inbound_limit
+    );
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::context::SetOutboundChainLimit;
+
+pub fn handler(ctx: Context<SetOutboundChainLimit>, chain: u16, limit: u64) -> Result<()> {
+    let outbound_chain_limit = &mut ctx.accounts.outbound_chain_limit;
+    outbound_chain_limit.mint = ctx.accounts.registered_token.mint;
+    outbound_chain_limit.chain = chain;
+    outbound_chain_limit
+        .limit
+        .set_limit(limit, Clock::get()?.unix_timestamp);
+
+    msg!(
+        "Outbound chain limit updated: mint={}, chain={}, limit={}",
+        outbound_chain_limit.mint,
+        chain,
+        limit
+    );
+
+    Ok(())
+}
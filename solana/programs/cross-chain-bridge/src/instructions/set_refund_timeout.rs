@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+use crate::{context::SetRefundTimeout, error::BridgeError};
+
+pub fn handler(ctx: Context<SetRefundTimeout>, refund_timeout: i64) -> Result<()> {
+    require!(refund_timeout > 0, BridgeError::InvalidRefundTimeout);
+
+    ctx.accounts.refund_config.refund_timeout = refund_timeout;
+
+    msg!("Refund timeout updated: {}", refund_timeout);
+
+    Ok(())
+}
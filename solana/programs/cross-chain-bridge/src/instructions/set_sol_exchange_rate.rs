@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::{context::SetSolExchangeRate, error::BridgeError};
+
+pub fn handler(
+    ctx: Context<SetSolExchangeRate>,
+    sol_exchange_rate: u64,
+    min_gas_dropoff: u64,
+    max_gas_dropoff: u64,
+) -> Result<()> {
+    require!(
+        min_gas_dropoff <= max_gas_dropoff,
+        BridgeError::InvalidGasDropoff
+    );
+
+    let registered_token = &mut ctx.accounts.registered_token;
+    registered_token.sol_exchange_rate = sol_exchange_rate;
+    registered_token.min_gas_dropoff = min_gas_dropoff;
+    registered_token.max_gas_dropoff = max_gas_dropoff;
+
+    msg!(
+        "SOL exchange rate updated: mint={}, rate={}, gas_dropoff={}..={}",
+        registered_token.mint,
+        sol_exchange_rate,
+        min_gas_dropoff,
+        max_gas_dropoff
+    );
+
+    Ok(())
+}
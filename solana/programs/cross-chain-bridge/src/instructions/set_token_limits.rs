@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{context::SetTokenLimits, error::BridgeError};
+
+pub fn handler(
+    ctx: Context<SetTokenLimits>,
+    min_amount: u64,
+    max_amount: u64,
+    outbound_limit: u64,
+) -> Result<()> {
+    require!(
+        max_amount == 0 || min_amount <= max_amount,
+        BridgeError::InvalidTokenLimits
+    );
+
+    let registered_token = &mut ctx.accounts.registered_token;
+    registered_token.min_amount = min_amount;
+    registered_token.max_amount = max_amount;
+    registered_token
+        .outbound_limit
+        .set_limit(outbound_limit, Clock::get()?.unix_timestamp);
+
+    msg!(
+        "Token limits updated: mint={}, min={}, max={}, outbound_limit={}",
+        registered_token.mint,
+        min_amount,
+        max_amount,
+        outbound_limit
+    );
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{context::SetTokenRelayerFee, error::BridgeError};
+
+pub fn handler(
+    ctx: Context<SetTokenRelayerFee>,
+    relayer_fee: u32,
+    relayer_fee_precision: u32,
+    min_relayer_fee: u64,
+) -> Result<()> {
+    require!(
+        relayer_fee < relayer_fee_precision,
+        BridgeError::InvalidRelayerFee,
+    );
+
+    let token_relayer_fee = &mut ctx.accounts.token_relayer_fee;
+    token_relayer_fee.mint = ctx.accounts.registered_token.mint;
+    token_relayer_fee.relayer_fee = relayer_fee;
+    token_relayer_fee.relayer_fee_precision = relayer_fee_precision;
+    token_relayer_fee.min_relayer_fee = min_relayer_fee;
+
+    msg!(
+        "Token relayer fee updated: mint={}, fee={}/{}, min={}",
+        token_relayer_fee.mint,
+        relayer_fee,
+        relayer_fee_precision,
+        min_relayer_fee
+    );
+
+    Ok(())
+}
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+use crate::{context::SetTransferStatus, state::TransferStatus};
+
+pub fn handler(
+    ctx: Context<SetTransferStatus>,
+    _wormhole_message: Pubkey,
+    status: TransferStatus,
+) -> Result<()> {
+    let receipt = &mut ctx.accounts.transfer_receipt;
+    receipt.status = status;
+
+    msg!("Transfer {} status updated: {:?}", receipt.sequence, status);
+
+    Ok(())
+}
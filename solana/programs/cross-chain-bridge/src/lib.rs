@@ -2,11 +2,16 @@ use anchor_lang::prelude::*;
 
 pub mod context;
 pub mod error;
+pub mod event;
+pub mod inbound;
 pub mod instructions;
 pub mod message;
+pub mod outbound;
 pub mod state;
+pub mod token;
 
 pub use context::*;
+pub use message::{RecipientKind, TransferOptions};
 pub use state::*;
 
 declare_id!("5HVG1XFoN3KXa6gcFkCs7iFcHvtsbmY6drvP34S1mwn4");
@@ -33,6 +38,235 @@ pub mod cross_chain_bridge {
         instructions::register_foreign_contract::handler(ctx, chain, address)
     }
 
+    /// Registers a mint for bridging, or updates the directions it can be
+    /// bridged in. Only SPL Token mints: Token Bridge cannot custody
+    /// Token-2022 mints, so they are not supported.
+    /// Only the program owner can call this instruction.
+    pub fn register_token(
+        ctx: Context<RegisterToken>,
+        outbound_enabled: bool,
+        inbound_enabled: bool,
+    ) -> Result<()> {
+        instructions::register_token::handler(ctx, outbound_enabled, inbound_enabled)
+    }
+
+    /// Sets the per-transfer bounds and the rolling 24h outbound cap of a
+    /// registered token, in the mint's units. Zero disables the maximum and
+    /// the cap. Only the program owner can call this instruction.
+    pub fn set_token_limits(
+        ctx: Context<SetTokenLimits>,
+        min_amount: u64,
+        max_amount: u64,
+        outbound_limit: u64,
+    ) -> Result<()> {
+        instructions::set_token_limits::handler(ctx, min_amount, max_amount, outbound_limit)
+    }
+
+    /// Sets the rolling 24h outbound cap of a registered token towards a
+    /// foreign chain. Zero disables the cap.
+    /// Only the program owner can call this instruction.
+    pub fn set_outbound_chain_limit(
+        ctx: Context<SetOutboundChainLimit>,
+        chain: u16,
+        limit: u64,
+    ) -> Result<()> {
+        instructions::set_outbound_chain_limit::handler(ctx, chain, limit)
+    }
+
+    /// Returns the transfer bounds and remaining outbound capacity of a
+    /// registered token towards a foreign chain, for simulation by clients.
+    pub fn get_outbound_capacity(
+        ctx: Context<GetOutboundCapacity>,
+        chain: u16,
+    ) -> Result<OutboundCapacity> {
+        instructions::get_outbound_capacity::handler(ctx, chain)
+    }
+
+    /// Quotes the redemption on Solana, by a relayer, of an inbound transfer
+    /// of `amount` from `source_chain` to a wallet recipient: the amount after
+    /// truncation, the truncation dust, the relayer's share (relayer fee,
+    /// ATA rent recoup and gas drop-off cost, together capped by
+    /// `max_relayer_fee`), the gas dropped off for `gas_dropoff` and the net
+    /// recipient amount. `source_chain` selects its relayer fee override.
+    /// For simulation by clients and programs.
+    pub fn quote_inbound_transfer(
+        ctx: Context<QuoteInboundTransfer>,
+        source_chain: u16,
+        amount: u64,
+        max_relayer_fee: Option<u64>,
+        gas_dropoff: u64,
+    ) -> Result<inbound::TransferQuote> {
+        instructions::quote_inbound_transfer::handler(
+            ctx,
+            source_chain,
+            amount,
+            max_relayer_fee,
+            gas_dropoff,
+        )
+    }
+
+    /// Sets the inbound threshold and rolling 24h inbound cap of a registered
+    /// token. Larger redeems, or redeems beyond the cap, are delayed.
+    /// Zero disables the threshold and the cap.
+    /// Only the program owner can call this instruction.
+    pub fn set_inbound_limits(
+        ctx: Context<SetInboundLimits>,
+        inbound_threshold: u64,
+        inbound_limit: u64,
+    ) -> Result<()> {
+        instructions::set_inbound_limits::handler(ctx, inbound_threshold, inbound_limit)
+    }
+
+    /// Sets how many base units of a registered token are worth one SOL, and
+    /// the smallest and largest gas drop-off per redeem in lamports. Relayers
+    /// are reimbursed in tokens at this rate for the rent of recipient ATAs
+    /// they create and for the gas drop-offs they deliver. A drop-off to an
+    /// empty wallet is raised to the rent-exempt minimum, or skipped if that
+    /// is above the largest. A zero rate disables both.
+    /// Only the program owner can call this instruction.
+    pub fn set_sol_exchange_rate(
+        ctx: Context<SetSolExchangeRate>,
+        sol_exchange_rate: u64,
+        min_gas_dropoff: u64,
+        max_gas_dropoff: u64,
+    ) -> Result<()> {
+        instructions::set_sol_exchange_rate::handler(
+            ctx,
+            sol_exchange_rate,
+            min_gas_dropoff,
+            max_gas_dropoff,
+        )
+    }
+
+    /// Sets the guardian allowed to hold delayed transfers and the delay
+    /// before they can be released.
+    /// Only the program owner can call this instruction.
+    pub fn set_governor(
+        ctx: Context<SetGovernor>,
+        guardian: Pubkey,
+        release_delay: i64,
+    ) -> Result<()> {
+        instructions::set_governor::handler(ctx, guardian, release_delay)
+    }
+
+    /// Releases a delayed transfer to its recipient once the delay has passed
+    /// and unless it is held. The amount counts against the inbound cap.
+    /// A call recipient program is called once released; the remaining
+    /// accounts are passed to that call. Anyone can call this instruction.
+    pub fn release_pending_transfer<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReleasePendingTransfer<'info>>,
+        vaa_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::release_pending_transfer::handler(ctx, vaa_hash)
+    }
+
+    /// Holds a delayed transfer for review, or lifts the hold. A held
+    /// transfer cannot be released until the hold is lifted, but can be
+    /// cancelled.
+    /// Only the governor's guardian can call this instruction.
+    pub fn hold_pending_transfer(
+        ctx: Context<HoldPendingTransfer>,
+        vaa_hash: [u8; 32],
+        held: bool,
+    ) -> Result<()> {
+        instructions::hold_pending_transfer::handler(ctx, vaa_hash, held)
+    }
+
+    /// Cancels a delayed native token transfer, held or not, and sends the
+    /// escrowed tokens back to its origin chain with a refund payload, like
+    /// `refund_native_transfer`. Dust Token Bridge cannot carry goes to the
+    /// recipient.
+    /// Only the governor's guardian can call this instruction.
+    pub fn cancel_pending_native_transfer(
+        ctx: Context<CancelPendingNativeTransfer>,
+        vaa_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::cancel_pending_native_transfer::handler(ctx, vaa_hash)
+    }
+
+    /// Cancels a delayed wrapped token transfer, under the same conditions
+    /// as `cancel_pending_native_transfer`.
+    pub fn cancel_pending_wrapped_transfer(
+        ctx: Context<CancelPendingWrappedTransfer>,
+        vaa_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::cancel_pending_wrapped_transfer::handler(ctx, vaa_hash)
+    }
+
+    /// Sets how long after its VAA was signed an unredeemed inbound transfer
+    /// becomes refundable.
+    /// Only the program owner can call this instruction.
+    pub fn set_refund_timeout(ctx: Context<SetRefundTimeout>, refund_timeout: i64) -> Result<()> {
+        instructions::set_refund_timeout::handler(ctx, refund_timeout)
+    }
+
+    /// Sends an unredeemed native token transfer back to its origin chain
+    /// once the refund timeout has passed, or at any time if the payload has
+    /// a refund address. The refund goes to the payload's refund address, or
+    /// else its sender; legacy payloads are refunded to the contract that
+    /// sent them.
+    /// Only the payload's recipient or the program owner can call this
+    /// instruction.
+    pub fn refund_native_transfer(
+        ctx: Context<RefundNativeTransfer>,
+        vaa_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::refund_native_transfer::handler(ctx, vaa_hash)
+    }
+
+    /// Sends an unredeemed wrapped token transfer back to its origin chain,
+    /// under the same conditions as `refund_native_transfer`.
+    pub fn refund_wrapped_transfer(
+        ctx: Context<RefundWrappedTransfer>,
+        vaa_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::refund_wrapped_transfer::handler(ctx, vaa_hash)
+    }
+
+    /// Updates the status of an outbound transfer receipt, e.g. once the
+    /// transfer is redeemed on the target chain.
+    /// Only the program owner can call this instruction.
+    pub fn set_transfer_status(
+        ctx: Context<SetTransferStatus>,
+        wormhole_message: Pubkey,
+        status: TransferStatus,
+    ) -> Result<()> {
+        instructions::set_transfer_status::handler(ctx, wormhole_message, status)
+    }
+
+    /// Closes an outbound transfer receipt and returns its rent to the
+    /// sender. Only the transfer's sender can call this instruction.
+    pub fn close_transfer_receipt(
+        ctx: Context<CloseTransferReceipt>,
+        wormhole_message: Pubkey,
+    ) -> Result<()> {
+        instructions::close_transfer_receipt::handler(ctx, wormhole_message)
+    }
+
+    /// Closes a redeem receipt and returns its rent to the relayer who
+    /// created it. Only the transfer's recipient or the program owner can
+    /// call this instruction.
+    pub fn close_redeem_receipt(
+        ctx: Context<CloseRedeemReceipt>,
+        vaa_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::close_redeem_receipt::handler(ctx, vaa_hash)
+    }
+
+    /// Attests a Solana mint through Token Bridge, posting an `AssetMeta`
+    /// message so its wrapped asset can be created on foreign chains.
+    /// Only the program owner can call this instruction.
+    pub fn attest_token(ctx: Context<AttestToken>, batch_id: u32) -> Result<()> {
+        instructions::attest_token::handler(ctx, batch_id)
+    }
+
+    /// Creates the Token Bridge wrapped mint of a foreign token from its
+    /// posted `AssetMeta` VAA.
+    /// Only the program owner can call this instruction.
+    pub fn create_wrapped(ctx: Context<CreateWrapped>, vaa_hash: [u8; 32]) -> Result<()> {
+        instructions::create_wrapped::handler(ctx, vaa_hash)
+    }
+
     /// Updates the relayer fee configuration.
     pub fn update_relayer_fee(
         ctx: Context<UpdateRelayerFee>,
@@ -42,13 +276,72 @@ pub mod cross_chain_bridge {
         instructions::update_relayer_fee::handler(ctx, relayer_fee, relayer_fee_precision)
     }
 
-    /// Sends native tokens with a payload to a foreign chain.
-    pub fn send_native_tokens_with_payload(
-        ctx: Context<SendNativeTokensWithPayload>,
+    /// Sets the relayer fee for transfers from a foreign chain, overriding
+    /// the global relayer fee.
+    /// Only the program owner can call this instruction.
+    pub fn set_chain_relayer_fee(
+        ctx: Context<SetChainRelayerFee>,
+        chain: u16,
+        relayer_fee: u32,
+        relayer_fee_precision: u32,
+    ) -> Result<()> {
+        instructions::set_chain_relayer_fee::handler(ctx, chain, relayer_fee, relayer_fee_precision)
+    }
+
+    /// Sets the relayer fee of a registered token, overriding the chain and
+    /// global relayer fees, with a flat minimum in the mint's units.
+    /// Only the program owner can call this instruction.
+    pub fn set_token_relayer_fee(
+        ctx: Context<SetTokenRelayerFee>,
+        relayer_fee: u32,
+        relayer_fee_precision: u32,
+        min_relayer_fee: u64,
+    ) -> Result<()> {
+        instructions::set_token_relayer_fee::handler(
+            ctx,
+            relayer_fee,
+            relayer_fee_precision,
+            min_relayer_fee,
+        )
+    }
+
+    /// Sends native tokens with a payload to a foreign chain. `options` sets a
+    /// relayer fee cap and tip for the target chain. Only the amount truncated
+    /// to 8 decimals is taken; with `require_exact`, the send fails instead.
+    pub fn send_native_tokens_with_payload<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SendNativeTokensWithPayload<'info>>,
+        batch_id: u32,
+        amount: u64,
+        recipient_address: [u8; 32],
+        recipient_chain: u16,
+        options: Option<TransferOptions>,
+        require_exact: bool,
+    ) -> Result<()> {
+        instructions::send_native_tokens::handler(
+            ctx,
+            batch_id,
+            amount,
+            recipient_address,
+            recipient_chain,
+            message::TransferMode::WithPayload(options),
+            require_exact,
+        )
+    }
+
+    /// Sends native tokens as a standard Token Bridge transfer straight to
+    /// `recipient_address`, redeemable by any Token Bridge client on the
+    /// target chain. `relayer_fee`, in the token's units, goes to whoever
+    /// completes the transfer. The target chain must still be registered,
+    /// and the same outbound limits apply. `require_exact` works as in
+    /// `send_native_tokens_with_payload`.
+    pub fn send_native_tokens<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SendNativeTokensWithPayload<'info>>,
         batch_id: u32,
         amount: u64,
         recipient_address: [u8; 32],
         recipient_chain: u16,
+        relayer_fee: u64,
+        require_exact: bool,
     ) -> Result<()> {
         instructions::send_native_tokens::handler(
             ctx,
@@ -56,24 +349,81 @@ pub mod cross_chain_bridge {
             amount,
             recipient_address,
             recipient_chain,
+            message::TransferMode::Plain { relayer_fee },
+            require_exact,
+        )
+    }
+
+    /// Wraps `amount` lamports into SOL and sends them with a payload to a
+    /// foreign chain, so the payer needs no wSOL token account. Only the
+    /// lamports truncated to 8 decimals are taken; with `require_exact`, the
+    /// send fails instead.
+    pub fn send_sol_with_payload(
+        ctx: Context<SendSolWithPayload>,
+        batch_id: u32,
+        amount: u64,
+        recipient_address: [u8; 32],
+        recipient_chain: u16,
+        options: Option<TransferOptions>,
+        require_exact: bool,
+    ) -> Result<()> {
+        instructions::send_sol::handler(
+            ctx,
+            batch_id,
+            amount,
+            recipient_address,
+            recipient_chain,
+            options,
+            require_exact,
         )
     }
 
-    /// Redeems a native token transfer from a foreign chain.
-    pub fn redeem_native_transfer_with_payload(
-        ctx: Context<RedeemNativeTransferWithPayload>,
+    /// Redeems a native token transfer from a foreign chain. With `unwrap`,
+    /// a SOL transfer is paid to the recipient as lamports instead of wSOL.
+    /// A call recipient program is called once paid; the remaining accounts
+    /// are passed to that call and used for nothing else.
+    /// Redeeming a refund of an outbound transfer marks that transfer's
+    /// receipt refunded, if passed.
+    pub fn redeem_native_transfer_with_payload<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RedeemNativeTransferWithPayload<'info>>,
         vaa_hash: [u8; 32],
+        unwrap: bool,
     ) -> Result<()> {
-        instructions::redeem_native_transfer::handler(ctx, vaa_hash)
+        instructions::redeem_native_transfer::handler(ctx, vaa_hash, unwrap)
     }
 
-    /// Sends wrapped tokens with a payload to a foreign chain.
+    /// Sends wrapped tokens with a payload to a foreign chain. `options` sets a
+    /// relayer fee cap and tip for the target chain.
     pub fn send_wrapped_tokens_with_payload(
         ctx: Context<SendWrappedTokensWithPayload>,
         batch_id: u32,
         amount: u64,
         recipient_address: [u8; 32],
         recipient_chain: u16,
+        options: Option<TransferOptions>,
+    ) -> Result<()> {
+        instructions::send_wrapped_tokens::handler(
+            ctx,
+            batch_id,
+            amount,
+            recipient_address,
+            recipient_chain,
+            message::TransferMode::WithPayload(options),
+        )
+    }
+
+    /// Sends wrapped tokens as a standard Token Bridge transfer straight to
+    /// `recipient_address`, redeemable by any Token Bridge client on the
+    /// target chain. `relayer_fee`, in the token's units, goes to whoever
+    /// completes the transfer. The target chain must still be registered,
+    /// and the same outbound limits apply.
+    pub fn send_wrapped_tokens(
+        ctx: Context<SendWrappedTokensWithPayload>,
+        batch_id: u32,
+        amount: u64,
+        recipient_address: [u8; 32],
+        recipient_chain: u16,
+        relayer_fee: u64,
     ) -> Result<()> {
         instructions::send_wrapped_tokens::handler(
             ctx,
@@ -81,12 +431,17 @@ pub mod cross_chain_bridge {
             amount,
             recipient_address,
             recipient_chain,
+            message::TransferMode::Plain { relayer_fee },
         )
     }
 
-    /// Redeems a wrapped token transfer from a foreign chain.
-    pub fn redeem_wrapped_transfer_with_payload(
-        ctx: Context<RedeemWrappedTransferWithPayload>,
+    /// Redeems a wrapped token transfer from a foreign chain. A call recipient
+    /// program is called once paid; the remaining accounts are passed to that
+    /// call and used for nothing else.
+    /// Redeeming a refund of an outbound transfer marks that transfer's
+    /// receipt refunded, if passed.
+    pub fn redeem_wrapped_transfer_with_payload<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RedeemWrappedTransferWithPayload<'info>>,
         vaa_hash: [u8; 32],
     ) -> Result<()> {
         instructions::redeem_wrapped_transfer::handler(ctx, vaa_hash)
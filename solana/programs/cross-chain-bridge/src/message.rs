@@ -1,13 +1,245 @@
-use anchor_lang::{AnchorDeserialize, AnchorSerialize};
+use anchor_lang::prelude::*;
 use std::io;
 use wormhole_anchor_sdk::token_bridge;
 use wormhole_io::Readable;
 
 const PAYLOAD_ID_HELLO: u8 = 1;
+// Payload ID 2 (recipient with a relayer fee cap and tip) is reserved: it was
+// never emitted and the EVM contract does not accept it.
+const PAYLOAD_ID_HELLO_V2: u8 = 3;
+const PAYLOAD_ID_REFUND: u8 = 4;
 
-#[derive(Clone, Copy)]
+/// Maximum length of the app data carried by a `HelloV2` payload.
+pub const APP_DATA_MAX_LENGTH: usize = 256;
+
+/// Seed of the PDA a program declares to receive transfers as
+/// `RecipientKind::ProgramPda`.
+pub const SEED_PREFIX_BRIDGE_RECIPIENT: &[u8; 16] = b"bridge_recipient";
+
+/// How the redeeming side resolves the recipient of a `HelloV2` payload.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum RecipientKind {
+    /// A wallet, paid to its ATA.
+    #[default]
+    Wallet,
+    /// A token account of the transferred mint, paid directly.
+    TokenAccount,
+    /// A program, paid to the ATA of its `bridge_recipient` PDA.
+    ProgramPda,
+    /// A program paid like `ProgramPda`, then called with the transfer
+    /// details and the payload's app data as call data. Transfers delayed
+    /// by the governor make the call when released.
+    Call,
+}
+
+/// Instruction discriminator of `receive_bridged_transfer`, the instruction a
+/// `Call` recipient program implements. Matches what Anchor derives.
+pub const TRANSFER_CALL_DISCRIMINATOR: [u8; 8] = [67, 147, 138, 73, 1, 90, 254, 187];
+
+/// Seed of the PDA that signs the call into a `Call` recipient program, so
+/// the program can tell the call came from the bridge. It is the authority
+/// of nothing.
+pub const SEED_PREFIX_TRANSFER_CALL: &[u8; 13] = b"transfer_call";
+
+/// Arguments of the call into a `Call` recipient program.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TransferCall {
+    /// Amount paid to the recipient program's token account.
+    pub amount: u64,
+    /// Wormhole chain ID the transfer came from.
+    pub source_chain: u16,
+    /// Origin sender on the source chain.
+    pub sender: [u8; 32],
+    /// Call data from the payload's app data.
+    pub data: Vec<u8>,
+}
+
+impl TransferCall {
+    pub const MAXIMUM_SIZE: usize = 8  // amount
+        + 2   // source_chain
+        + 32  // sender
+        + 4   // data length
+        + APP_DATA_MAX_LENGTH;
+}
+
+/// Optional sender-specified settings of an outbound transfer.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Eq)]
+pub struct TransferOptions {
+    /// Maximum relayer fee the recipient pays on the target chain
+    /// (normalized, i.e. 8 decimals). Zero for no cap.
+    pub max_relayer_fee: u64,
+    /// Relayer fee tip for the target chain's relayer (normalized).
+    pub target_relayer_fee: u64,
+    /// Native gas, in the target chain's smallest unit, the relayer drops off
+    /// to the recipient in exchange for tokens.
+    pub gas_dropoff: u64,
+    /// Reference ID for reconciliation.
+    pub memo: [u8; 32],
+    /// Origin chain address refunds are sent to instead of the sender, if
+    /// non-zero.
+    pub refund_address: [u8; 32],
+    /// App-defined data for integrators, at most `APP_DATA_MAX_LENGTH` bytes.
+    pub app_data: Vec<u8>,
+    /// How the recipient address is resolved on the target chain.
+    pub recipient_kind: RecipientKind,
+}
+
+/// How an outbound transfer is sent through Token Bridge.
+pub enum TransferMode {
+    /// Transfer with payload to the target chain's foreign contract, which
+    /// pays out the payload's recipient.
+    WithPayload(Option<TransferOptions>),
+    /// Standard transfer straight to the recipient address, redeemable by
+    /// any Token Bridge client. `relayer_fee` (in the token's units) goes to
+    /// whoever completes the transfer.
+    Plain { relayer_fee: u64 },
+}
+
+/// Token Bridge transfer payloads.
+///
+/// * `Hello`: Payload ID == 1. Recipient only; the redeeming chain's relayer
+///   fee applies.
+/// * `HelloV2`: Payload ID == 3. Adds the recipient kind, the origin sender,
+///   a gas drop-off, a memo, a refund address and app data. Its layout is
+///   frozen and matches `CrossChainBridgeMessages.encodePayloadV2` on EVM:
+///   `[3][recipient 32][kind 1][sender 32][max fee u64][target fee u64]`
+///   `[gas drop-off u64][memo 32][refund address 32][len u16][app data]`,
+///   integers big-endian. Any layout change takes a new payload ID.
+/// * `Refund`: Payload ID == 4. Sends an undeliverable transfer back to the
+///   origin chain's sender (or refund address), with the sequence of the
+///   refunded transfer.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum TokenMessage {
-    Hello { recipient: [u8; 32] },
+    Hello {
+        recipient: [u8; 32],
+    },
+    HelloV2 {
+        recipient: [u8; 32],
+        recipient_kind: RecipientKind,
+        sender: [u8; 32],
+        max_relayer_fee: u64,
+        target_relayer_fee: u64,
+        gas_dropoff: u64,
+        memo: [u8; 32],
+        refund_address: [u8; 32],
+        app_data: Vec<u8>,
+    },
+    Refund {
+        recipient: [u8; 32],
+        sequence: u64,
+    },
+}
+
+impl TokenMessage {
+    /// Build the payload of an outbound transfer from `sender`.
+    pub fn new(recipient: [u8; 32], sender: [u8; 32], options: Option<TransferOptions>) -> Self {
+        match options {
+            Some(options) => TokenMessage::HelloV2 {
+                recipient,
+                recipient_kind: options.recipient_kind,
+                sender,
+                max_relayer_fee: options.max_relayer_fee,
+                target_relayer_fee: options.target_relayer_fee,
+                gas_dropoff: options.gas_dropoff,
+                memo: options.memo,
+                refund_address: options.refund_address,
+                app_data: options.app_data,
+            },
+            None => TokenMessage::Hello { recipient },
+        }
+    }
+
+    pub fn recipient(&self) -> &[u8; 32] {
+        match self {
+            TokenMessage::Hello { recipient }
+            | TokenMessage::HelloV2 { recipient, .. }
+            | TokenMessage::Refund { recipient, .. } => recipient,
+        }
+    }
+
+    /// How the recipient is resolved (a wallet for legacy payloads).
+    pub fn recipient_kind(&self) -> RecipientKind {
+        match self {
+            TokenMessage::HelloV2 { recipient_kind, .. } => *recipient_kind,
+            _ => RecipientKind::Wallet,
+        }
+    }
+
+    /// Sender-set relayer fee cap (normalized), if any. A zero cap, as sent
+    /// with default options, means no cap.
+    pub fn max_relayer_fee(&self) -> Option<u64> {
+        match self {
+            TokenMessage::Hello { .. } | TokenMessage::Refund { .. } => None,
+            TokenMessage::HelloV2 {
+                max_relayer_fee, ..
+            } => (*max_relayer_fee != 0).then_some(*max_relayer_fee),
+        }
+    }
+
+    /// Origin sender (zero for legacy payloads).
+    pub fn sender(&self) -> [u8; 32] {
+        match self {
+            TokenMessage::HelloV2 { sender, .. } => *sender,
+            _ => [0; 32],
+        }
+    }
+
+    /// Requested gas drop-off (zero for legacy payloads).
+    pub fn gas_dropoff(&self) -> u64 {
+        match self {
+            TokenMessage::HelloV2 { gas_dropoff, .. } => *gas_dropoff,
+            _ => 0,
+        }
+    }
+
+    /// Memo (zero for legacy payloads).
+    pub fn memo(&self) -> [u8; 32] {
+        match self {
+            TokenMessage::HelloV2 { memo, .. } => *memo,
+            _ => [0; 32],
+        }
+    }
+
+    /// Where a refund of this transfer goes: the refund address if set, else
+    /// the origin sender. Legacy payloads carry neither and are refunded to
+    /// `from_address`, the Token Bridge sender of the transfer. Zero for a
+    /// refund, which is never sent back again.
+    pub fn refund_recipient(&self, from_address: &[u8; 32]) -> [u8; 32] {
+        match self {
+            TokenMessage::HelloV2 {
+                sender,
+                refund_address,
+                ..
+            } => {
+                if *refund_address != [0; 32] {
+                    *refund_address
+                } else if *sender != [0; 32] {
+                    *sender
+                } else {
+                    *from_address
+                }
+            }
+            TokenMessage::Hello { .. } => *from_address,
+            TokenMessage::Refund { .. } => [0; 32],
+        }
+    }
+
+    /// Whether the payload names a refund address, which makes the transfer
+    /// refundable before the refund timeout.
+    pub fn has_refund_address(&self) -> bool {
+        matches!(
+            self,
+            TokenMessage::HelloV2 { refund_address, .. } if *refund_address != [0; 32]
+        )
+    }
+
+    /// App data (empty for legacy payloads).
+    pub fn app_data(&self) -> &[u8] {
+        match self {
+            TokenMessage::HelloV2 { app_data, .. } => app_data,
+            _ => &[],
+        }
+    }
 }
 
 impl AnchorSerialize for TokenMessage {
@@ -17,6 +249,43 @@ impl AnchorSerialize for TokenMessage {
                 PAYLOAD_ID_HELLO.serialize(writer)?;
                 recipient.serialize(writer)
             }
+            TokenMessage::HelloV2 {
+                recipient,
+                recipient_kind,
+                sender,
+                max_relayer_fee,
+                target_relayer_fee,
+                gas_dropoff,
+                memo,
+                refund_address,
+                app_data,
+            } => {
+                if app_data.len() > APP_DATA_MAX_LENGTH {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("app data exceeds {APP_DATA_MAX_LENGTH} bytes"),
+                    ));
+                }
+                PAYLOAD_ID_HELLO_V2.serialize(writer)?;
+                recipient.serialize(writer)?;
+                recipient_kind.serialize(writer)?;
+                sender.serialize(writer)?;
+                max_relayer_fee.to_be_bytes().serialize(writer)?;
+                target_relayer_fee.to_be_bytes().serialize(writer)?;
+                gas_dropoff.to_be_bytes().serialize(writer)?;
+                memo.serialize(writer)?;
+                refund_address.serialize(writer)?;
+                (app_data.len() as u16).to_be_bytes().serialize(writer)?;
+                writer.write_all(app_data)
+            }
+            TokenMessage::Refund {
+                recipient,
+                sequence,
+            } => {
+                PAYLOAD_ID_REFUND.serialize(writer)?;
+                recipient.serialize(writer)?;
+                sequence.to_be_bytes().serialize(writer)
+            }
         }
     }
 }
@@ -27,6 +296,42 @@ impl AnchorDeserialize for TokenMessage {
             PAYLOAD_ID_HELLO => Ok(TokenMessage::Hello {
                 recipient: Readable::read(reader)?,
             }),
+            PAYLOAD_ID_HELLO_V2 => {
+                let recipient = Readable::read(reader)?;
+                let recipient_kind = RecipientKind::deserialize_reader(reader)?;
+                let sender = Readable::read(reader)?;
+                let max_relayer_fee = u64::read(reader)?;
+                let target_relayer_fee = u64::read(reader)?;
+                let gas_dropoff = u64::read(reader)?;
+                let memo = Readable::read(reader)?;
+                let refund_address = Readable::read(reader)?;
+
+                let length = u16::read(reader)? as usize;
+                if length > APP_DATA_MAX_LENGTH {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("app data exceeds {APP_DATA_MAX_LENGTH} bytes"),
+                    ));
+                }
+                let mut app_data = vec![0; length];
+                reader.read_exact(&mut app_data)?;
+
+                Ok(TokenMessage::HelloV2 {
+                    recipient,
+                    recipient_kind,
+                    sender,
+                    max_relayer_fee,
+                    target_relayer_fee,
+                    gas_dropoff,
+                    memo,
+                    refund_address,
+                    app_data,
+                })
+            }
+            PAYLOAD_ID_REFUND => Ok(TokenMessage::Refund {
+                recipient: Readable::read(reader)?,
+                sequence: u64::read(reader)?,
+            }),
             _ => Err(io::Error::new(
                 io::ErrorKind::InvalidInput,
                 "invalid payload ID",
@@ -36,3 +341,138 @@ impl AnchorDeserialize for TokenMessage {
 }
 
 pub type PostedTokenMessage = token_bridge::PostedTransferWith<TokenMessage>;
+
+#[cfg(test)]
+fn hello_v2(app_data: Vec<u8>) -> TokenMessage {
+    TokenMessage::HelloV2 {
+        recipient: [0x11; 32],
+        recipient_kind: RecipientKind::TokenAccount,
+        sender: [0x22; 32],
+        max_relayer_fee: 0x0102030405060708,
+        target_relayer_fee: 9,
+        gas_dropoff: 10,
+        memo: [0x33; 32],
+        refund_address: [0x44; 32],
+        app_data,
+    }
+}
+
+#[test]
+fn token_message_roundtrip_serialization() {
+    let messages = [
+        TokenMessage::Hello {
+            recipient: [0x11; 32],
+        },
+        hello_v2(vec![0xde, 0xad, 0xbe, 0xef]),
+        hello_v2(vec![0; APP_DATA_MAX_LENGTH]),
+        TokenMessage::Refund {
+            recipient: [0x11; 32],
+            sequence: 42,
+        },
+    ];
+    for message in messages {
+        let encoded = message.try_to_vec().unwrap();
+        assert_eq!(TokenMessage::try_from_slice(&encoded).unwrap(), message);
+    }
+}
+
+/// Same bytes as `test_PayloadV2GoldenVector` in the EVM tests.
+#[test]
+fn hello_v2_golden_vector() {
+    let mut expected = vec![3];
+    expected.extend([0x11; 32]);
+    expected.push(1);
+    expected.extend([0x22; 32]);
+    expected.extend([1, 2, 3, 4, 5, 6, 7, 8]);
+    expected.extend(9u64.to_be_bytes());
+    expected.extend(10u64.to_be_bytes());
+    expected.extend([0x33; 32]);
+    expected.extend([0x44; 32]);
+    expected.extend([0, 4, 0xde, 0xad, 0xbe, 0xef]);
+    assert_eq!(expected.len(), 160);
+
+    let message = hello_v2(vec![0xde, 0xad, 0xbe, 0xef]);
+    assert_eq!(message.try_to_vec().unwrap(), expected);
+    assert_eq!(TokenMessage::try_from_slice(&expected).unwrap(), message);
+}
+
+#[test]
+fn hello_v2_rejects_oversized_app_data() {
+    assert!(hello_v2(vec![0; APP_DATA_MAX_LENGTH + 1])
+        .try_to_vec()
+        .is_err());
+
+    // Declare one byte more than allowed, with the bytes present.
+    let mut encoded = hello_v2(vec![]).try_to_vec().unwrap();
+    let length = encoded.len() - 2;
+    encoded[length..].copy_from_slice(&(APP_DATA_MAX_LENGTH as u16 + 1).to_be_bytes());
+    encoded.extend(vec![0; APP_DATA_MAX_LENGTH + 1]);
+    assert!(TokenMessage::try_from_slice(&encoded).is_err());
+}
+
+#[test]
+fn token_message_rejects_reserved_payload_id() {
+    let mut encoded = TokenMessage::Hello {
+        recipient: [0x11; 32],
+    }
+    .try_to_vec()
+    .unwrap();
+    encoded[0] = 2;
+    assert!(TokenMessage::try_from_slice(&encoded).is_err());
+}
+
+#[test]
+fn refund_recipient_fallbacks() {
+    let from_address = [0x55; 32];
+
+    let legacy = TokenMessage::Hello {
+        recipient: [0x11; 32],
+    };
+    assert_eq!(legacy.refund_recipient(&from_address), from_address);
+    assert!(!legacy.has_refund_address());
+
+    let mut message = hello_v2(vec![]);
+    assert_eq!(message.refund_recipient(&from_address), [0x44; 32]);
+    assert!(message.has_refund_address());
+
+    if let TokenMessage::HelloV2 { refund_address, .. } = &mut message {
+        *refund_address = [0; 32];
+    }
+    assert_eq!(message.refund_recipient(&from_address), [0x22; 32]);
+    assert!(!message.has_refund_address());
+
+    let refund = TokenMessage::Refund {
+        recipient: [0x11; 32],
+        sequence: 1,
+    };
+    assert_eq!(refund.refund_recipient(&from_address), [0; 32]);
+}
+
+/// Same bytes as `test_EncodeDecodeRefundPayload` in the EVM tests.
+#[test]
+fn refund_golden_vector() {
+    let mut expected = vec![4];
+    expected.extend([0x11; 32]);
+    expected.extend(42u64.to_be_bytes());
+
+    let message = TokenMessage::Refund {
+        recipient: [0x11; 32],
+        sequence: 42,
+    };
+    assert_eq!(message.try_to_vec().unwrap(), expected);
+    assert_eq!(TokenMessage::try_from_slice(&expected).unwrap(), message);
+}
+
+#[test]
+fn zero_max_relayer_fee_is_uncapped() {
+    let mut message = hello_v2(vec![]);
+    assert_eq!(message.max_relayer_fee(), Some(0x0102030405060708));
+
+    if let TokenMessage::HelloV2 {
+        max_relayer_fee, ..
+    } = &mut message
+    {
+        *max_relayer_fee = 0;
+    }
+    assert_eq!(message.max_relayer_fee(), None);
+}
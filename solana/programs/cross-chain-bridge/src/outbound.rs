@@ -0,0 +1,217 @@
+use anchor_lang::prelude::*;
+use wormhole_anchor_sdk::{app, token_bridge};
+
+use crate::{
+    error::BridgeError,
+    message::{TokenMessage, TransferMode, APP_DATA_MAX_LENGTH},
+    state::{OutboundChainLimit, RegisteredToken, TransferReceipt, TransferStatus},
+};
+
+/// An outbound transfer, checked, counted and recorded the same way by every
+/// send instruction.
+pub struct OutboundTransfer {
+    /// Payer of the transfer.
+    pub sender: Pubkey,
+    /// Mint of the sent tokens.
+    pub mint: Pubkey,
+    /// Decimals of the mint.
+    pub decimals: u8,
+    /// Amount bridged, already truncated to what Token Bridge carries.
+    pub amount: u64,
+    /// Target chain.
+    pub recipient_chain: u16,
+    /// Recipient on the target chain.
+    pub recipient_address: [u8; 32],
+    /// How the transfer is sent through Token Bridge.
+    pub mode: TransferMode,
+}
+
+impl OutboundTransfer {
+    /// Check the target and the relayer fees and app data against the amount.
+    pub fn verify(&self) -> Result<()> {
+        require!(self.amount > 0, BridgeError::ZeroBridgeAmount);
+        require!(
+            app::is_valid_peer(self.recipient_chain, &self.recipient_address),
+            BridgeError::InvalidRecipient,
+        );
+
+        match &self.mode {
+            TransferMode::WithPayload(Some(options)) => {
+                require!(
+                    options.app_data.len() <= APP_DATA_MAX_LENGTH,
+                    BridgeError::InvalidAppData
+                );
+                require!(
+                    options.target_relayer_fee
+                        <= token_bridge::normalize_amount(self.amount, self.decimals),
+                    BridgeError::InvalidRelayerFee
+                );
+            }
+            TransferMode::WithPayload(None) => {}
+            TransferMode::Plain { relayer_fee } => {
+                require!(*relayer_fee <= self.amount, BridgeError::InvalidRelayerFee);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Count the transfer against the token's outbound cap and, if set, the
+    /// target chain's limit.
+    pub fn consume_limits(
+        &self,
+        registered_token: &AccountInfo,
+        outbound_chain_limit: &AccountInfo,
+        now: i64,
+    ) -> Result<()> {
+        let mut token = RegisteredToken::load(registered_token)?;
+        token.consume_outbound(self.amount, now)?;
+        token.save(registered_token)?;
+
+        if let Some(mut chain_limit) = OutboundChainLimit::load(outbound_chain_limit)? {
+            require!(
+                chain_limit.limit.consume(self.amount, now),
+                BridgeError::ChainOutboundLimitExceeded
+            );
+            chain_limit.save(outbound_chain_limit)?;
+        }
+
+        Ok(())
+    }
+
+    /// Fill in the transfer's receipt. `sequence` is the Token Bridge
+    /// emitter's current sequence, which Wormhole assigns to the message.
+    pub fn record(&self, receipt: &mut TransferReceipt, sequence: u64, now: i64) {
+        receipt.sender = self.sender;
+        receipt.mint = self.mint;
+        receipt.amount = self.amount;
+        receipt.recipient_chain = self.recipient_chain;
+        receipt.recipient = self.recipient_address;
+        receipt.sequence = sequence;
+        receipt.timestamp = now;
+        receipt.status = TransferStatus::Sent;
+    }
+
+    /// Bridge native tokens from the tmp account in `accounts.from`, with a
+    /// payload to `foreign_contract` signed by `sender`, or plain.
+    pub fn bridge_native<'info>(
+        self,
+        token_bridge_program: AccountInfo<'info>,
+        accounts: token_bridge::TransferNative<'info>,
+        sender: AccountInfo<'info>,
+        batch_id: u32,
+        foreign_contract: [u8; 32],
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        match self.mode {
+            TransferMode::WithPayload(options) => token_bridge::transfer_native_with_payload(
+                CpiContext::new_with_signer(
+                    token_bridge_program,
+                    accounts.with_payload(sender),
+                    signer_seeds,
+                ),
+                batch_id,
+                self.amount,
+                foreign_contract,
+                self.recipient_chain,
+                TokenMessage::new(self.recipient_address, self.sender.to_bytes(), options)
+                    .try_to_vec()?,
+                &crate::ID,
+            ),
+            TransferMode::Plain { relayer_fee } => token_bridge::transfer_native(
+                CpiContext::new_with_signer(token_bridge_program, accounts, signer_seeds),
+                batch_id,
+                self.amount,
+                relayer_fee,
+                self.recipient_address,
+                self.recipient_chain,
+            ),
+        }
+    }
+
+    /// Bridge wrapped tokens from the tmp account in `accounts.from`, with a
+    /// payload to `foreign_contract` signed by `sender`, or plain.
+    pub fn bridge_wrapped<'info>(
+        self,
+        token_bridge_program: AccountInfo<'info>,
+        accounts: token_bridge::TransferWrapped<'info>,
+        sender: AccountInfo<'info>,
+        batch_id: u32,
+        foreign_contract: [u8; 32],
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        match self.mode {
+            TransferMode::WithPayload(options) => token_bridge::transfer_wrapped_with_payload(
+                CpiContext::new_with_signer(
+                    token_bridge_program,
+                    accounts.with_payload(sender),
+                    signer_seeds,
+                ),
+                batch_id,
+                self.amount,
+                foreign_contract,
+                self.recipient_chain,
+                TokenMessage::new(self.recipient_address, self.sender.to_bytes(), options)
+                    .try_to_vec()?,
+                &crate::ID,
+            ),
+            TransferMode::Plain { relayer_fee } => token_bridge::transfer_wrapped(
+                CpiContext::new_with_signer(token_bridge_program, accounts, signer_seeds),
+                batch_id,
+                self.amount,
+                relayer_fee,
+                self.recipient_address,
+                self.recipient_chain,
+            ),
+        }
+    }
+}
+
+#[test]
+fn outbound_transfer_verify() {
+    use crate::message::TransferOptions;
+
+    let transfer = |amount, mode| OutboundTransfer {
+        sender: Pubkey::default(),
+        mint: Pubkey::default(),
+        decimals: 9,
+        amount,
+        recipient_chain: 2,
+        recipient_address: [1; 32],
+        mode,
+    };
+    let options = |target_relayer_fee, app_data_len| {
+        TransferMode::WithPayload(Some(TransferOptions {
+            target_relayer_fee,
+            app_data: vec![0; app_data_len],
+            ..Default::default()
+        }))
+    };
+
+    assert!(transfer(1_000, TransferMode::WithPayload(None))
+        .verify()
+        .is_ok());
+    assert!(transfer(0, TransferMode::WithPayload(None))
+        .verify()
+        .is_err());
+
+    // The tip is normalized to 8 decimals: 1_000 units of 9 decimals is 100.
+    assert!(transfer(1_000, options(100, APP_DATA_MAX_LENGTH))
+        .verify()
+        .is_ok());
+    assert!(transfer(1_000, options(101, 0)).verify().is_err());
+    assert!(transfer(1_000, options(0, APP_DATA_MAX_LENGTH + 1))
+        .verify()
+        .is_err());
+
+    assert!(transfer(1_000, TransferMode::Plain { relayer_fee: 1_000 })
+        .verify()
+        .is_ok());
+    assert!(transfer(1_000, TransferMode::Plain { relayer_fee: 1_001 })
+        .verify()
+        .is_err());
+
+    let mut to_self = transfer(1_000, TransferMode::WithPayload(None));
+    to_self.recipient_chain = 0;
+    assert!(to_self.verify().is_err());
+}
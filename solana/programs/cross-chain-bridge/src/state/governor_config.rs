@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+/// Governor account data. Inbound transfers above a registered token's
+/// threshold or beyond its inbound cap are held in escrow for
+/// `release_delay` seconds, during which the guardian can hold them for
+/// review or cancel them.
+#[account]
+#[derive(Default)]
+pub struct GovernorConfig {
+    /// Key allowed to hold and cancel pending releases.
+    pub guardian: Pubkey,
+    /// Seconds a delayed transfer is held before it can be released.
+    pub release_delay: i64,
+}
+
+impl GovernorConfig {
+    pub const MAXIMUM_SIZE: usize = 8  // discriminator
+        + 32  // guardian
+        + 8;  // release_delay
+
+    /// Seed prefix for PDA derivation.
+    pub const SEED_PREFIX: &'static [u8; 8] = b"governor";
+}
@@ -1,7 +1,25 @@
 pub use foreign_contract::*;
+pub use governor_config::*;
+pub use outbound_chain_limit::*;
+pub use pending_release::*;
+pub use rate_limit::*;
+pub use redeem_receipt::*;
 pub use redeemer_config::*;
+pub use refund_config::*;
+pub use registered_token::*;
+pub use relayer_fee::*;
 pub use sender_config::*;
+pub use transfer_receipt::*;
 
 pub mod foreign_contract;
+pub mod governor_config;
+pub mod outbound_chain_limit;
+pub mod pending_release;
+pub mod rate_limit;
+pub mod redeem_receipt;
 pub mod redeemer_config;
+pub mod refund_config;
+pub mod registered_token;
+pub mod relayer_fee;
 pub mod sender_config;
+pub mod transfer_receipt;
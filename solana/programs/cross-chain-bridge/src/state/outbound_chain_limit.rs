@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use super::RateLimit;
+
+/// Rolling outbound cap of a registered token towards one foreign chain.
+/// Transfers to chains without one are only subject to the token's cap.
+#[account]
+#[derive(Default)]
+pub struct OutboundChainLimit {
+    /// Mint of the registered token.
+    pub mint: Pubkey,
+    /// Destination chain.
+    pub chain: u16,
+    /// Outbound cap towards `chain`.
+    pub limit: RateLimit,
+}
+
+impl OutboundChainLimit {
+    pub const MAXIMUM_SIZE: usize = 8  // discriminator
+        + 32  // mint
+        + 2   // chain
+        + RateLimit::LEN;
+
+    /// Seed prefix for PDA derivation.
+    pub const SEED_PREFIX: &'static [u8; 14] = b"outbound_limit";
+
+    /// Deserialize a limit from its PDA, if it was created.
+    pub fn load(info: &AccountInfo) -> Result<Option<Self>> {
+        if info.data_is_empty() {
+            return Ok(None);
+        }
+        Self::try_deserialize(&mut &info.try_borrow_data()?[..]).map(Some)
+    }
+
+    /// Serialize the limit back into its PDA.
+    pub fn save(&self, info: &AccountInfo) -> Result<()> {
+        self.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])
+    }
+}
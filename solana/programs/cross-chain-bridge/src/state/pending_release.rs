@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::BridgeError,
+    message::{TokenMessage, TransferCall},
+};
+
+/// Call into a `Call` recipient program, made when the transfer is released.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PendingCall {
+    /// Program named by the payload.
+    pub program: Pubkey,
+    /// Call arguments, with the released amount.
+    pub call: TransferCall,
+}
+
+/// Delayed inbound transfer. The recipient's tokens sit in the escrow token
+/// account of the same VAA until released. The guardian can hold a pending
+/// release for review, then lift the hold or cancel the transfer, sending
+/// the tokens back to the origin chain like a refund.
+#[account]
+#[derive(Default)]
+pub struct PendingRelease {
+    /// Mint of the held tokens.
+    pub mint: Pubkey,
+    /// Recipient of the transfer.
+    pub recipient: Pubkey,
+    /// Token account the tokens are released to.
+    pub recipient_token_account: Pubkey,
+    /// Amount held in escrow.
+    pub amount: u64,
+    /// Payer of the redeem, refunded the rent when the release is closed.
+    pub payer: Pubkey,
+    /// Time after which anyone can release the tokens.
+    pub release_time: i64,
+    /// Held by the guardian: cannot be released until the hold is lifted.
+    pub held: bool,
+    /// Chain the transfer was sent from, which a cancel refunds to.
+    pub emitter_chain: u16,
+    /// Token Bridge sequence of the transfer.
+    pub sequence: u64,
+    /// Origin chain address a cancel refunds to. Zero if the transfer is a
+    /// refund itself, which cannot be cancelled.
+    pub refund_recipient: [u8; 32],
+    /// Call made on release, for a call recipient.
+    pub call: Option<PendingCall>,
+}
+
+impl PendingRelease {
+    pub const MAXIMUM_SIZE: usize = 8  // discriminator
+        + 32  // mint
+        + 32  // recipient
+        + 32  // recipient_token_account
+        + 8   // amount
+        + 32  // payer
+        + 8   // release_time
+        + 1   // held
+        + 2   // emitter_chain
+        + 8   // sequence
+        + 32  // refund_recipient
+        + 1 + 32 + TransferCall::MAXIMUM_SIZE;  // call
+
+    /// Seed prefix for PDA derivation.
+    pub const SEED_PREFIX: &'static [u8; 15] = b"pending_release";
+
+    /// Check the transfer can be released at `now`: not held, and due.
+    pub fn check_release(&self, now: i64) -> Result<()> {
+        require!(!self.held, BridgeError::TransferHeld);
+        require!(now >= self.release_time, BridgeError::ReleaseNotDue);
+        Ok(())
+    }
+
+    /// Refund payload sending the tokens back when the transfer is cancelled.
+    pub fn refund_message(&self) -> Result<TokenMessage> {
+        require!(self.refund_recipient != [0; 32], BridgeError::NotRefundable);
+        Ok(TokenMessage::Refund {
+            recipient: self.refund_recipient,
+            sequence: self.sequence,
+        })
+    }
+}
+
+#[test]
+fn pending_release_fits_largest_call() {
+    let pending_release = PendingRelease {
+        call: Some(PendingCall {
+            program: Pubkey::new_unique(),
+            call: TransferCall {
+                amount: u64::MAX,
+                source_chain: u16::MAX,
+                sender: [1; 32],
+                data: vec![1; crate::message::APP_DATA_MAX_LENGTH],
+            },
+        }),
+        ..Default::default()
+    };
+    assert_eq!(
+        pending_release.try_to_vec().unwrap().len() + 8,
+        PendingRelease::MAXIMUM_SIZE
+    );
+}
+
+#[test]
+fn pending_release_after_unhold() {
+    let mut pending_release = PendingRelease {
+        release_time: 100,
+        held: true,
+        ..Default::default()
+    };
+    assert!(pending_release.check_release(100).is_err());
+
+    pending_release.held = false;
+    assert!(pending_release.check_release(99).is_err());
+    assert!(pending_release.check_release(100).is_ok());
+}
+
+#[test]
+fn pending_release_cancel_refunds_sender() {
+    let mut pending_release = PendingRelease {
+        held: true,
+        sequence: 42,
+        ..Default::default()
+    };
+    assert!(pending_release.refund_message().is_err());
+
+    pending_release.refund_recipient = [0x22; 32];
+    assert_eq!(
+        pending_release.refund_message().unwrap().try_to_vec().unwrap(),
+        TokenMessage::Refund {
+            recipient: [0x22; 32],
+            sequence: 42,
+        }
+        .try_to_vec()
+        .unwrap()
+    );
+}
@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+
+/// Rolling 24h transfer cap. Capacity refills linearly over the window, so
+/// a fully used cap is available again `RateLimit::DURATION` seconds later.
+#[derive(Default, AnchorSerialize, AnchorDeserialize, Copy, Clone, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Maximum amount per window. Zero disables the cap.
+    pub limit: u64,
+    /// Capacity left right after the last transfer.
+    pub capacity_at_last_tx: u64,
+    /// Time of the last transfer.
+    pub last_tx_timestamp: i64,
+}
+
+impl RateLimit {
+    pub const LEN: usize = 8 + 8 + 8;
+
+    /// Length of the rolling window in seconds.
+    pub const DURATION: i64 = 24 * 60 * 60;
+
+    /// Capacity available at `now`. `u64::MAX` if the cap is disabled.
+    pub fn capacity(&self, now: i64) -> u64 {
+        if self.limit == 0 {
+            return u64::MAX;
+        }
+
+        let elapsed = now.saturating_sub(self.last_tx_timestamp).max(0) as u128;
+        let refill = self.limit as u128 * elapsed / Self::DURATION as u128;
+        (self.capacity_at_last_tx as u128 + refill).min(self.limit as u128) as u64
+    }
+
+    /// Use `amount` of the capacity. Returns `false` if it is not available.
+    pub fn consume(&mut self, amount: u64, now: i64) -> bool {
+        if self.limit == 0 {
+            return true;
+        }
+
+        let capacity = self.capacity(now);
+        if amount > capacity {
+            return false;
+        }
+        self.capacity_at_last_tx = capacity - amount;
+        self.last_tx_timestamp = now;
+        true
+    }
+
+    /// Use `amount` of the capacity, or all of it if less is available.
+    pub fn consume_saturating(&mut self, amount: u64, now: i64) {
+        if self.limit == 0 {
+            return;
+        }
+
+        self.capacity_at_last_tx = self.capacity(now).saturating_sub(amount);
+        self.last_tx_timestamp = now;
+    }
+
+    /// Change the limit, keeping the amount already used in the window.
+    pub fn set_limit(&mut self, limit: u64, now: i64) {
+        let capacity = if self.limit == 0 {
+            limit
+        } else {
+            let used = self.limit - self.capacity(now);
+            limit.saturating_sub(used)
+        };
+
+        self.limit = limit;
+        self.capacity_at_last_tx = capacity;
+        self.last_tx_timestamp = now;
+    }
+}
+
+/// Outbound limits of a mint, as returned by `get_outbound_capacity`.
+/// Amounts are in the mint's (denormalized) units; `u64::MAX` means no limit.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, PartialEq, Eq)]
+pub struct OutboundCapacity {
+    /// Minimum amount per transfer.
+    pub min_amount: u64,
+    /// Maximum amount per transfer.
+    pub max_amount: u64,
+    /// Amount that can currently be sent to any chain.
+    pub token_capacity: u64,
+    /// Amount that can currently be sent to the queried chain.
+    pub chain_capacity: u64,
+}
+
+#[test]
+fn rate_limit_refills_linearly() {
+    let mut rate_limit = RateLimit::default();
+    rate_limit.set_limit(1_000, 0);
+
+    assert_eq!(rate_limit.capacity(0), 1_000);
+    assert!(rate_limit.consume(1_000, 0));
+    assert_eq!(rate_limit.capacity(0), 0);
+
+    // Half the window refills half the limit, the full window all of it.
+    assert_eq!(rate_limit.capacity(RateLimit::DURATION / 2), 500);
+    assert_eq!(rate_limit.capacity(RateLimit::DURATION), 1_000);
+    assert_eq!(rate_limit.capacity(10 * RateLimit::DURATION), 1_000);
+
+    // Timestamps before the last transfer do not refill or underflow.
+    assert_eq!(rate_limit.capacity(-1), 0);
+}
+
+#[test]
+fn rate_limit_boundaries() {
+    let mut rate_limit = RateLimit::default();
+
+    // A zero limit disables the cap.
+    assert_eq!(rate_limit.capacity(0), u64::MAX);
+    assert!(rate_limit.consume(u64::MAX, 0));
+
+    rate_limit.set_limit(100, 0);
+    assert!(!rate_limit.consume(101, 0));
+    assert!(rate_limit.consume(100, 0));
+    assert!(!rate_limit.consume(1, 0));
+    assert!(rate_limit.consume(0, 0));
+
+    // Exactly the refilled amount can be used again.
+    let one_unit = RateLimit::DURATION / 100;
+    assert!(!rate_limit.consume(2, one_unit));
+    assert!(rate_limit.consume(1, one_unit));
+
+    // Lowering the limit keeps what was already used in the window.
+    let mut rate_limit = RateLimit::default();
+    rate_limit.set_limit(100, 0);
+    assert!(rate_limit.consume(60, 0));
+    rate_limit.set_limit(80, 0);
+    assert_eq!(rate_limit.capacity(0), 20);
+    rate_limit.set_limit(200, 0);
+    assert_eq!(rate_limit.capacity(0), 140);
+    rate_limit.set_limit(50, 0);
+    assert_eq!(rate_limit.capacity(0), 0);
+}
+
+#[test]
+fn rate_limit_consume_saturating() {
+    let mut rate_limit = RateLimit::default();
+    rate_limit.set_limit(100, 0);
+
+    rate_limit.consume_saturating(30, 0);
+    assert_eq!(rate_limit.capacity(0), 70);
+    rate_limit.consume_saturating(1_000, 0);
+    assert_eq!(rate_limit.capacity(0), 0);
+    assert_eq!(rate_limit.capacity(RateLimit::DURATION), 100);
+}
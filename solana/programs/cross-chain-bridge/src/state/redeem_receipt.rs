@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::message::APP_DATA_MAX_LENGTH;
+
+/// Redeemed transfer record, created by every redeem so the payout split and
+/// the payload's sender, memo and app data can be read on-chain afterwards.
+/// Keyed by VAA hash. The recipient or the program owner can close it,
+/// refunding the rent to the relayer.
+#[account]
+#[derive(Default)]
+pub struct RedeemReceipt {
+    /// Chain the transfer was sent from.
+    pub emitter_chain: u16,
+    /// Token Bridge sequence of the transfer.
+    pub sequence: u64,
+    /// Mint of the redeemed tokens.
+    pub mint: Pubkey,
+    /// Recipient of the transfer.
+    pub recipient: Pubkey,
+    /// Payer of the redeem transaction, who received the relayer fee.
+    pub relayer: Pubkey,
+    /// Amount transferred, relayer fee included.
+    pub amount: u64,
+    /// Amount paid to the relayer, gas drop-off cost included.
+    pub relayer_fee: u64,
+    /// Decimals of `amount` and `relayer_fee`, i.e. the mint's decimals.
+    pub decimals: u8,
+    /// Time the transfer was redeemed.
+    pub timestamp: i64,
+    /// Origin sender (zero for legacy payloads).
+    pub sender: [u8; 32],
+    /// Memo (zero for legacy payloads).
+    pub memo: [u8; 32],
+    /// App data (empty for legacy payloads).
+    pub app_data: Vec<u8>,
+}
+
+impl RedeemReceipt {
+    pub const MAXIMUM_SIZE: usize = 8  // discriminator
+        + 2   // emitter_chain
+        + 8   // sequence
+        + 32  // mint
+        + 32  // recipient
+        + 32  // relayer
+        + 8   // amount
+        + 8   // relayer_fee
+        + 1   // decimals
+        + 8   // timestamp
+        + 32  // sender
+        + 32  // memo
+        + 4 + APP_DATA_MAX_LENGTH; // app_data
+
+    /// Seed prefix for PDA derivation.
+    pub const SEED_PREFIX: &'static [u8; 14] = b"redeem_receipt";
+}
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
-use wormhole_anchor_sdk::token_bridge;
+use wormhole_anchor_sdk::{app::WormholeApp, token_bridge};
+
+use super::{ChainRelayerFee, TokenRelayerFee};
 
 /// Addresses of Token Bridge accounts needed for inbound transfers.
 #[derive(Default, AnchorSerialize, AnchorDeserialize, Copy, Clone, PartialEq, Eq)]
@@ -45,4 +47,108 @@ impl RedeemerConfig {
     pub fn compute_relayer_amount(&self, amount: u64) -> u64 {
         (amount * self.relayer_fee as u64) / self.relayer_fee_precision as u64
     }
+
+    /// Calculate relayer fee amount from transfer amount, applying overrides.
+    /// The token's override takes precedence over the source chain's, which
+    /// takes precedence over the global fee. The token's flat minimum applies
+    /// on top of any percentage fee, capped at the transfer amount.
+    pub fn resolve_relayer_amount(
+        &self,
+        amount: u64,
+        chain_fee: Option<&ChainRelayerFee>,
+        token_fee: Option<&TokenRelayerFee>,
+    ) -> u64 {
+        let (relayer_fee, relayer_fee_precision) = match (token_fee, chain_fee) {
+            (Some(token_fee), _) => (token_fee.relayer_fee, token_fee.relayer_fee_precision),
+            (None, Some(chain_fee)) => (chain_fee.relayer_fee, chain_fee.relayer_fee_precision),
+            (None, None) => (self.relayer_fee, self.relayer_fee_precision),
+        };
+        let min_relayer_fee = token_fee.map(|fee| fee.min_relayer_fee).unwrap_or_default();
+
+        let percentage_fee =
+            (amount as u128 * relayer_fee as u128 / relayer_fee_precision as u128) as u64;
+        percentage_fee.max(min_relayer_fee).min(amount)
+    }
+}
+
+#[test]
+fn relayer_fee_resolution_precedence() {
+    let config = RedeemerConfig {
+        relayer_fee: 1,
+        relayer_fee_precision: 100,
+        ..Default::default()
+    };
+    let chain_fee = ChainRelayerFee {
+        chain: 2,
+        relayer_fee: 2,
+        relayer_fee_precision: 100,
+    };
+    let token_fee = TokenRelayerFee {
+        relayer_fee: 3,
+        relayer_fee_precision: 100,
+        ..Default::default()
+    };
+
+    assert_eq!(config.resolve_relayer_amount(10_000, None, None), 100);
+    assert_eq!(
+        config.resolve_relayer_amount(10_000, Some(&chain_fee), None),
+        200
+    );
+    assert_eq!(
+        config.resolve_relayer_amount(10_000, None, Some(&token_fee)),
+        300
+    );
+    assert_eq!(
+        config.resolve_relayer_amount(10_000, Some(&chain_fee), Some(&token_fee)),
+        300
+    );
+}
+
+#[test]
+fn relayer_fee_flat_minimum() {
+    let config = RedeemerConfig {
+        relayer_fee: 1,
+        relayer_fee_precision: 100,
+        ..Default::default()
+    };
+    let chain_fee = ChainRelayerFee {
+        chain: 2,
+        relayer_fee: 50,
+        relayer_fee_precision: 100,
+    };
+    let token_fee = TokenRelayerFee {
+        relayer_fee: 1,
+        relayer_fee_precision: 100,
+        min_relayer_fee: 500,
+        ..Default::default()
+    };
+
+    // The minimum applies when the percentage fee is below it...
+    assert_eq!(
+        config.resolve_relayer_amount(10_000, None, Some(&token_fee)),
+        500
+    );
+    // ...not when it is above it...
+    assert_eq!(
+        config.resolve_relayer_amount(100_000, None, Some(&token_fee)),
+        1_000
+    );
+    // ...and never exceeds the transfer amount.
+    assert_eq!(
+        config.resolve_relayer_amount(400, Some(&chain_fee), Some(&token_fee)),
+        400
+    );
+    assert_eq!(config.resolve_relayer_amount(0, None, Some(&token_fee)), 0);
+
+    // Large amounts do not overflow.
+    assert_eq!(
+        config.resolve_relayer_amount(u64::MAX, Some(&chain_fee), None),
+        u64::MAX / 2
+    );
+}
+
+impl WormholeApp for RedeemerConfig {
+    fn owner(&self) -> &Pubkey {
+        &self.owner
+    }
 }
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+/// Refund account data. Inbound transfers that are still unredeemed
+/// `refund_timeout` seconds after their VAA was signed can be sent back to
+/// the origin chain. Transfers with a refund address can be sent back at any
+/// time.
+#[account]
+#[derive(Default)]
+pub struct RefundConfig {
+    /// Seconds after the VAA timestamp before a transfer can be refunded.
+    pub refund_timeout: i64,
+}
+
+impl RefundConfig {
+    pub const MAXIMUM_SIZE: usize = 8  // discriminator
+        + 8;  // refund_timeout
+
+    /// Seed prefix for PDA derivation.
+    pub const SEED_PREFIX: &'static [u8; 13] = b"refund_config";
+
+    /// Whether a transfer whose VAA was signed at `timestamp` can be
+    /// refunded at `now`.
+    pub fn is_refundable(&self, timestamp: u32, now: i64) -> bool {
+        now >= i64::from(timestamp) + self.refund_timeout
+    }
+}
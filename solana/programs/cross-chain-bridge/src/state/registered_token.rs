@@ -0,0 +1,218 @@
+use anchor_lang::prelude::*;
+
+use super::RateLimit;
+use crate::error::BridgeError;
+
+const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+/// Registered token account data. Only mints with a registration can be
+/// bridged, in the directions enabled by the owner.
+#[account]
+#[derive(Default)]
+pub struct RegisteredToken {
+    /// Mint of the registered token (native or Token Bridge wrapped).
+    pub mint: Pubkey,
+    /// Whether tokens of this mint can be sent to foreign chains.
+    pub outbound_enabled: bool,
+    /// Whether transfers of this mint from foreign chains can be redeemed.
+    pub inbound_enabled: bool,
+    /// Minimum amount per outbound transfer.
+    pub min_amount: u64,
+    /// Maximum amount per outbound transfer. Zero means no maximum.
+    pub max_amount: u64,
+    /// Outbound cap across all destination chains.
+    pub outbound_limit: RateLimit,
+    /// Inbound transfers above this amount are delayed. Zero means none are.
+    pub inbound_threshold: u64,
+    /// Inbound cap across all source chains. Transfers beyond it are delayed.
+    pub inbound_limit: RateLimit,
+    /// Token base units worth one SOL, used to reimburse relayers for ATA
+    /// rent and gas drop-offs. Zero means relayers are not reimbursed.
+    pub sol_exchange_rate: u64,
+    /// Smallest gas drop-off a relayer delivers, in lamports. Smaller
+    /// requests are raised to it.
+    pub min_gas_dropoff: u64,
+    /// Largest gas drop-off a relayer delivers per redeem, in lamports.
+    pub max_gas_dropoff: u64,
+}
+
+impl RegisteredToken {
+    pub const MAXIMUM_SIZE: usize = 8  // discriminator
+        + 32  // mint
+        + 1   // outbound_enabled
+        + 1   // inbound_enabled
+        + 8   // min_amount
+        + 8   // max_amount
+        + RateLimit::LEN // outbound_limit
+        + 8   // inbound_threshold
+        + RateLimit::LEN // inbound_limit
+        + 8   // sol_exchange_rate
+        + 8   // min_gas_dropoff
+        + 8;  // max_gas_dropoff
+
+    /// Seed prefix for PDA derivation.
+    pub const SEED_PREFIX: &'static [u8; 16] = b"registered_token";
+
+    /// Deserialize a registration from its PDA, which the instruction context
+    /// checked to be initialized.
+    pub fn load(info: &AccountInfo) -> Result<Self> {
+        Self::try_deserialize(&mut &info.try_borrow_data()?[..])
+    }
+
+    /// Serialize the registration back into its PDA.
+    pub fn save(&self, info: &AccountInfo) -> Result<()> {
+        self.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])
+    }
+
+    /// Check an outbound transfer of `amount` against the per-transfer
+    /// bounds and use it from the outbound cap.
+    pub fn consume_outbound(&mut self, amount: u64, now: i64) -> Result<()> {
+        require!(
+            self.outbound_enabled,
+            BridgeError::OutboundTransfersDisabled
+        );
+        require!(amount >= self.min_amount, BridgeError::AmountBelowMinimum);
+        require!(
+            self.max_amount == 0 || amount <= self.max_amount,
+            BridgeError::AmountAboveMaximum
+        );
+        require!(
+            self.outbound_limit.consume(amount, now),
+            BridgeError::OutboundLimitExceeded
+        );
+        Ok(())
+    }
+
+    /// Convert `lamports` into tokens at the owner-set exchange rate.
+    pub fn lamports_in_tokens(&self, lamports: u64) -> u64 {
+        let tokens = u128::from(lamports) * u128::from(self.sol_exchange_rate)
+            / u128::from(LAMPORTS_PER_SOL);
+        u64::try_from(tokens).unwrap_or(u64::MAX)
+    }
+
+    /// Fit a requested gas drop-off to the owner-set bounds. A recipient
+    /// holding `recipient_lamports` must end up with at least `rent_minimum`,
+    /// so a drop-off to an empty wallet is raised to that minimum. Returns
+    /// the lamports to drop off and their cost in tokens, both zero if
+    /// nothing was requested, drop-offs are disabled, the raised amount is
+    /// above the maximum or the cost exceeds the `available` tokens.
+    pub fn gas_dropoff(
+        &self,
+        requested: u64,
+        available: u64,
+        recipient_lamports: u64,
+        rent_minimum: u64,
+    ) -> (u64, u64) {
+        if requested == 0 || self.sol_exchange_rate == 0 {
+            return (0, 0);
+        }
+
+        let lamports = requested
+            .max(self.min_gas_dropoff)
+            .min(self.max_gas_dropoff)
+            .max(rent_minimum.saturating_sub(recipient_lamports));
+        let cost = self.lamports_in_tokens(lamports);
+        if lamports > self.max_gas_dropoff || cost > available {
+            return (0, 0);
+        }
+        (lamports, cost)
+    }
+
+    /// Use `amount` from the inbound cap. Returns `false` if the transfer
+    /// must be delayed instead: it is above the threshold or beyond the cap.
+    pub fn consume_inbound(&mut self, amount: u64, now: i64) -> bool {
+        if self.inbound_threshold > 0 && amount > self.inbound_threshold {
+            return false;
+        }
+        self.inbound_limit.consume(amount, now)
+    }
+
+    /// Count a released delayed transfer of `amount` against the inbound cap.
+    /// It is released even if the cap has less capacity left.
+    pub fn release_inbound(&mut self, amount: u64, now: i64) {
+        self.inbound_limit.consume_saturating(amount, now);
+    }
+}
+
+#[test]
+fn gas_dropoff_bounds() {
+    const RENT_MINIMUM: u64 = 890_880;
+
+    // 1 SOL is worth 2_000 tokens.
+    let token = RegisteredToken {
+        sol_exchange_rate: 2_000,
+        min_gas_dropoff: 1_000_000,
+        max_gas_dropoff: 10_000_000,
+        ..Default::default()
+    };
+
+    assert_eq!(token.lamports_in_tokens(LAMPORTS_PER_SOL), 2_000);
+    assert_eq!(token.lamports_in_tokens(5_000_000), 10);
+
+    // Within bounds, paid at the exchange rate.
+    assert_eq!(
+        token.gas_dropoff(5_000_000, 100, RENT_MINIMUM, RENT_MINIMUM),
+        (5_000_000, 10)
+    );
+    // Raised to the minimum, capped at the maximum.
+    assert_eq!(
+        token.gas_dropoff(1, 100, RENT_MINIMUM, RENT_MINIMUM),
+        (1_000_000, 2)
+    );
+    assert_eq!(
+        token.gas_dropoff(50_000_000, 100, RENT_MINIMUM, RENT_MINIMUM),
+        (10_000_000, 20)
+    );
+    // Not requested, or not affordable with the tokens left.
+    assert_eq!(
+        token.gas_dropoff(0, 100, RENT_MINIMUM, RENT_MINIMUM),
+        (0, 0)
+    );
+    assert_eq!(
+        token.gas_dropoff(5_000_000, 9, RENT_MINIMUM, RENT_MINIMUM),
+        (0, 0)
+    );
+
+    // Disabled without an exchange rate.
+    let disabled = RegisteredToken {
+        max_gas_dropoff: 10_000_000,
+        ..Default::default()
+    };
+    assert_eq!(
+        disabled.gas_dropoff(5_000_000, 100, RENT_MINIMUM, RENT_MINIMUM),
+        (0, 0)
+    );
+}
+
+#[test]
+fn gas_dropoff_to_empty_wallet_covers_rent() {
+    const RENT_MINIMUM: u64 = 890_880;
+
+    let token = RegisteredToken {
+        sol_exchange_rate: LAMPORTS_PER_SOL,
+        max_gas_dropoff: 10_000_000,
+        ..Default::default()
+    };
+
+    // An empty wallet gets at least the rent-exempt minimum.
+    assert_eq!(
+        token.gas_dropoff(1_000, u64::MAX, 0, RENT_MINIMUM),
+        (RENT_MINIMUM, RENT_MINIMUM)
+    );
+    assert_eq!(
+        token.gas_dropoff(2_000_000, u64::MAX, 0, RENT_MINIMUM),
+        (2_000_000, 2_000_000)
+    );
+    // A funded wallet gets what it asked for.
+    assert_eq!(
+        token.gas_dropoff(1_000, u64::MAX, RENT_MINIMUM, RENT_MINIMUM),
+        (1_000, 1_000)
+    );
+
+    // Skipped if the minimum is above the owner-set maximum.
+    let capped = RegisteredToken {
+        max_gas_dropoff: RENT_MINIMUM - 1,
+        ..token
+    };
+    assert_eq!(capped.gas_dropoff(1_000, u64::MAX, 0, RENT_MINIMUM), (0, 0));
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+/// Relayer fee override for transfers from one foreign chain. Takes
+/// precedence over the global fee in `RedeemerConfig`.
+#[account]
+#[derive(Default)]
+pub struct ChainRelayerFee {
+    /// Source chain.
+    pub chain: u16,
+    /// Relayer fee numerator.
+    pub relayer_fee: u32,
+    /// Relayer fee denominator (precision).
+    pub relayer_fee_precision: u32,
+}
+
+impl ChainRelayerFee {
+    pub const MAXIMUM_SIZE: usize = 8  // discriminator
+        + 2   // chain
+        + 4   // relayer_fee
+        + 4;  // relayer_fee_precision
+
+    /// Seed prefix for PDA derivation.
+    pub const SEED_PREFIX: &'static [u8; 17] = b"chain_relayer_fee";
+
+    /// Deserialize an override from its PDA, if it was created.
+    pub fn load(info: &AccountInfo) -> Result<Option<Self>> {
+        if info.data_is_empty() {
+            return Ok(None);
+        }
+        Self::try_deserialize(&mut &info.try_borrow_data()?[..]).map(Some)
+    }
+}
+
+/// Relayer fee override for one registered token. Takes precedence over
+/// the source chain's override and the global fee.
+#[account]
+#[derive(Default)]
+pub struct TokenRelayerFee {
+    /// Mint of the registered token.
+    pub mint: Pubkey,
+    /// Relayer fee numerator.
+    pub relayer_fee: u32,
+    /// Relayer fee denominator (precision).
+    pub relayer_fee_precision: u32,
+    /// Flat minimum relayer fee in the mint's units.
+    pub min_relayer_fee: u64,
+}
+
+impl TokenRelayerFee {
+    pub const MAXIMUM_SIZE: usize = 8  // discriminator
+        + 32  // mint
+        + 4   // relayer_fee
+        + 4   // relayer_fee_precision
+        + 8;  // min_relayer_fee
+
+    /// Seed prefix for PDA derivation.
+    pub const SEED_PREFIX: &'static [u8; 17] = b"token_relayer_fee";
+
+    /// Deserialize an override from its PDA, if it was created.
+    pub fn load(info: &AccountInfo) -> Result<Option<Self>> {
+        if info.data_is_empty() {
+            return Ok(None);
+        }
+        Self::try_deserialize(&mut &info.try_borrow_data()?[..]).map(Some)
+    }
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use wormhole_anchor_sdk::token_bridge;
+use wormhole_anchor_sdk::{app::WormholeApp, token_bridge};
 
 /// Addresses of Token Bridge accounts needed for outbound transfers.
 #[derive(Default, AnchorSerialize, AnchorDeserialize, Copy, Clone, PartialEq, Eq)]
@@ -42,3 +42,9 @@ impl SenderConfig {
     /// Seed prefix for PDA derivation - "sender"
     pub const SEED_PREFIX: &'static [u8; 6] = token_bridge::SEED_PREFIX_SENDER;
 }
+
+impl WormholeApp for SenderConfig {
+    fn owner(&self) -> &Pubkey {
+        &self.owner
+    }
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::BridgeError, message::TokenMessage};
+
+/// Lifecycle of an outbound transfer.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TransferStatus {
+    /// Posted to Wormhole.
+    #[default]
+    Sent,
+    /// Redeemed on the target chain. Solana cannot observe the redemption,
+    /// so only the owner sets this, through `set_transfer_status`.
+    Completed,
+    /// Sent back to Solana by the target chain. Set when the refund is
+    /// redeemed with the receipt passed along.
+    Refunded,
+}
+
+/// Outbound transfer record, created for every send. Seeded by the Wormhole
+/// message account, which is unique per transfer: looking a receipt up takes
+/// that account's key, which the sender keeps or reads from the send
+/// transaction.
+#[account]
+#[derive(Default)]
+pub struct TransferReceipt {
+    /// Payer of the transfer, who may close the receipt.
+    pub sender: Pubkey,
+    /// Mint of the sent tokens.
+    pub mint: Pubkey,
+    /// Amount bridged, after truncation.
+    pub amount: u64,
+    /// Target chain.
+    pub recipient_chain: u16,
+    /// Recipient on the target chain.
+    pub recipient: [u8; 32],
+    /// Token Bridge sequence of the transfer.
+    pub sequence: u64,
+    /// Time the transfer was sent.
+    pub timestamp: i64,
+    /// Current status.
+    pub status: TransferStatus,
+}
+
+impl TransferReceipt {
+    pub const MAXIMUM_SIZE: usize = 8  // discriminator
+        + 32  // sender
+        + 32  // mint
+        + 8   // amount
+        + 2   // recipient_chain
+        + 32  // recipient
+        + 8   // sequence
+        + 8   // timestamp
+        + 1;  // status
+
+    /// Seed prefix for PDA derivation.
+    pub const SEED_PREFIX: &'static [u8; 16] = b"transfer_receipt";
+
+    /// Mark the transfer refunded by `message`, a refund redeemed from
+    /// `emitter_chain` in `mint`. The refund must name this transfer.
+    pub fn mark_refunded(
+        &mut self,
+        message: &TokenMessage,
+        emitter_chain: u16,
+        mint: &Pubkey,
+    ) -> Result<()> {
+        let TokenMessage::Refund { sequence, .. } = message else {
+            return err!(BridgeError::InvalidTransferReceipt);
+        };
+        require!(
+            *sequence == self.sequence
+                && emitter_chain == self.recipient_chain
+                && *mint == self.mint,
+            BridgeError::InvalidTransferReceipt
+        );
+
+        self.status = TransferStatus::Refunded;
+        Ok(())
+    }
+}
+
+#[test]
+fn transfer_receipt_mark_refunded() {
+    let mint = Pubkey::new_unique();
+    let mut receipt = TransferReceipt {
+        mint,
+        recipient_chain: 2,
+        sequence: 42,
+        ..Default::default()
+    };
+    let refund = |sequence| TokenMessage::Refund {
+        recipient: [1; 32],
+        sequence,
+    };
+
+    assert!(receipt.mark_refunded(&refund(41), 2, &mint).is_err());
+    assert!(receipt.mark_refunded(&refund(42), 4, &mint).is_err());
+    assert!(receipt
+        .mark_refunded(&refund(42), 2, &Pubkey::new_unique())
+        .is_err());
+    assert!(receipt
+        .mark_refunded(&TokenMessage::Hello { recipient: [1; 32] }, 2, &mint)
+        .is_err());
+    assert_eq!(receipt.status, TransferStatus::Sent);
+
+    receipt.mark_refunded(&refund(42), 2, &mint).unwrap();
+    assert_eq!(receipt.status, TransferStatus::Refunded);
+}
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token, token_interface};
+
+use crate::{
+    error::BridgeError,
+    message::{RecipientKind, SEED_PREFIX_BRIDGE_RECIPIENT},
+};
+
+/// `transfer_checked` between accounts of a Token Bridge mint.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_checked<'info>(
+    token_program: &AccountInfo<'info>,
+    from: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    amount: u64,
+    decimals: u8,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            token_interface::TransferChecked {
+                from,
+                mint,
+                to,
+                authority,
+            },
+            signer_seeds,
+        ),
+        amount,
+        decimals,
+    )
+}
+
+/// Create the associated token account of `authority` for `mint` unless it
+/// already exists, paid by `payer`. Returns whether it was created.
+pub fn create_ata_if_needed<'info>(
+    payer: AccountInfo<'info>,
+    associated_token: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    associated_token_program: AccountInfo<'info>,
+) -> Result<bool> {
+    if !associated_token.data_is_empty() {
+        return Ok(false);
+    }
+
+    associated_token::create(CpiContext::new(
+        associated_token_program,
+        associated_token::Create {
+            payer,
+            associated_token,
+            authority,
+            mint,
+            system_program,
+            token_program,
+        },
+    ))?;
+
+    Ok(true)
+}
+
+/// Check the redeem's `recipient` and `recipient_token_account` against the
+/// recipient named by the payload. Returns whether the token account is the
+/// recipient's ATA, which may still need to be created, as opposed to an
+/// existing account paid directly.
+pub fn verify_recipient(
+    kind: RecipientKind,
+    payload_recipient: &[u8; 32],
+    recipient: &AccountInfo,
+    recipient_token_account: &AccountInfo,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Result<bool> {
+    match kind {
+        RecipientKind::Wallet => {
+            require!(
+                recipient.key().to_bytes() == *payload_recipient,
+                BridgeError::InvalidRecipient
+            );
+        }
+        RecipientKind::ProgramPda | RecipientKind::Call => {
+            let (pda, _) = Pubkey::find_program_address(
+                &[SEED_PREFIX_BRIDGE_RECIPIENT],
+                &Pubkey::from(*payload_recipient),
+            );
+            require!(recipient.key() == pda, BridgeError::InvalidRecipient);
+        }
+        RecipientKind::TokenAccount => {
+            require!(
+                recipient_token_account.key().to_bytes() == *payload_recipient,
+                BridgeError::InvalidRecipient
+            );
+            require!(
+                recipient_token_account.owner == token_program,
+                BridgeError::InvalidRecipientTokenAccount
+            );
+
+            let account = token_interface::TokenAccount::try_deserialize(
+                &mut &recipient_token_account.try_borrow_data()?[..],
+            )?;
+            require!(
+                account.mint == *mint,
+                BridgeError::InvalidRecipientTokenAccount
+            );
+            require!(
+                account.owner == recipient.key(),
+                BridgeError::InvalidRecipient
+            );
+
+            return Ok(false);
+        }
+    }
+
+    require!(
+        recipient_token_account.key()
+            == associated_token::get_associated_token_address_with_program_id(
+                recipient.key,
+                mint,
+                token_program
+            ),
+        BridgeError::InvalidRecipientAta
+    );
+
+    Ok(true)
+}
@@ -1,13 +1,20 @@
 use anchor_lang::prelude::*;
-use wormhole_anchor_sdk::wormhole::{self, program::Wormhole};
+use wormhole_anchor_sdk::{
+    app::{self, WormholeApp, WormholePeer},
+    wormhole::{self, program::Wormhole},
+};
 
 use crate::{
     error::MessengerError,
     message::MessengerMessage,
-    state::{Config, ForeignEmitter, Received, WormholeEmitter},
+    state::{
+        AllowedSender, BitmapReplay, Config, ExecutionTarget, ForeignEmitter, HandlerAccount,
+        HandlerRegistration, OutboxRecord, Received, RemoteAccount, RemoteNonceBitmap,
+        ReplayBitmap, SenderAllowlist, Sponsorship, WormholeEmitter,
+    },
 };
 
-pub const SEED_PREFIX_SENT: &[u8; 4] = b"sent";
+pub const SEED_PREFIX_SPONSOR_VAULT: &[u8; 13] = b"sponsor_vault";
 
 #[derive(Accounts)]
 pub struct Initialize<'info> {
@@ -62,16 +69,9 @@ pub struct Initialize<'info> {
     /// CHECK: Emitter's sequence account. Created by Wormhole on first message.
     pub wormhole_sequence: UncheckedAccount<'info>,
 
-    #[account(
-        mut,
-        seeds = [
-            SEED_PREFIX_SENT,
-            &wormhole::INITIAL_SEQUENCE.to_le_bytes()[..]
-        ],
-        bump,
-    )]
-    /// CHECK: Wormhole message account. Written by Wormhole program.
-    pub wormhole_message: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// Wormhole message account, a fresh keypair. Written by Wormhole program.
+    pub wormhole_message: Signer<'info>,
 
     pub clock: Sysvar<'info, Clock>,
     pub rent: Sysvar<'info, Rent>,
@@ -85,7 +85,7 @@ pub struct RegisterEmitter<'info> {
     pub owner: Signer<'info>,
 
     #[account(
-        has_one = owner @ MessengerError::OwnerOnly,
+        constraint = config.is_owner(&owner.key()) @ MessengerError::OwnerOnly,
         seeds = [Config::SEED_PREFIX],
         bump
     )]
@@ -143,20 +143,65 @@ pub struct SendMessage<'info> {
     )]
     pub wormhole_sequence: Account<'info, wormhole::SequenceTracker>,
 
+    #[account(mut)]
+    /// Wormhole message account, a fresh keypair. Written by Wormhole program.
+    pub wormhole_message: Signer<'info>,
+
     #[account(
-        mut,
+        init,
+        payer = payer,
         seeds = [
-            SEED_PREFIX_SENT,
-            &wormhole_sequence.next_value().to_le_bytes()[..]
+            OutboxRecord::SEED_PREFIX,
+            wormhole_message.key().as_ref()
         ],
         bump,
+        space = OutboxRecord::MAXIMUM_SIZE
     )]
-    /// CHECK: Wormhole Message account. Written by Wormhole program.
-    pub wormhole_message: UncheckedAccount<'info>,
+    pub outbox_record: Account<'info, OutboxRecord>,
 
     pub system_program: Program<'info, System>,
     pub clock: Sysvar<'info, Clock>,
     pub rent: Sysvar<'info, Rent>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_SPONSOR_VAULT],
+        bump,
+    )]
+    /// Pays the Wormhole fee, message rent and outbox record rent for
+    /// sponsored callers.
+    pub sponsor_vault: Option<SystemAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [
+            Sponsorship::CALLER_SEED_PREFIX,
+            payer.key().as_ref()
+        ],
+        bump,
+    )]
+    pub sponsorship: Option<Account<'info, Sponsorship>>,
+
+    /// Sender when it differs from the payer, e.g. a calling program's PDA.
+    pub sender: Option<Signer<'info>>,
+
+    #[account(
+        seeds = [SenderAllowlist::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: Sender allowlist switch. Empty until the owner first sets it.
+    pub sender_allowlist: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AllowedSender::SEED_PREFIX,
+            sender.as_ref().map_or(payer.key(), |sender| sender.key()).as_ref()
+        ],
+        bump,
+    )]
+    /// Allowlist entry of the sender. Required while the allowlist is enabled.
+    pub allowed_sender: Option<Account<'info, AllowedSender>>,
 }
 
 type MessengerVaa = wormhole::PostedVaa<MessengerMessage>;
@@ -191,7 +236,7 @@ pub struct ReceiveMessage<'info> {
             &posted.emitter_chain().to_le_bytes()[..]
         ],
         bump,
-        constraint = foreign_emitter.verify(posted.emitter_address()) @ MessengerError::InvalidForeignEmitter
+        constraint = foreign_emitter.verify(posted.emitter_chain(), posted.emitter_address()) @ MessengerError::InvalidForeignEmitter
     )]
     pub foreign_emitter: Account<'info, ForeignEmitter>,
 
@@ -208,7 +253,61 @@ pub struct ReceiveMessage<'info> {
     )]
     pub received: Account<'info, Received>,
 
+    #[account(
+        seeds = [
+            BitmapReplay::SEED_PREFIX,
+            &posted.emitter_chain().to_le_bytes()[..]
+        ],
+        bump,
+        constraint = bitmap_replay.data_is_empty() @ MessengerError::BitmapReplayEnabled
+    )]
+    /// CHECK: Must be empty, i.e. bitmap replay is off for the emitter chain.
+    pub bitmap_replay: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            ReplayBitmap::SEED_PREFIX,
+            &posted.emitter_chain().to_le_bytes()[..],
+            &app::replay_bitmap_index(posted.sequence()).to_le_bytes()[..]
+        ],
+        bump,
+    )]
+    /// CHECK: Replay bitmap covering the sequence, possibly uninitialized. The
+    /// sequence must not be marked, i.e. not consumed while bitmap replay was on.
+    pub replay_bitmap: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            HandlerRegistration::SEED_PREFIX,
+            &[posted.data().message_kind()][..]
+        ],
+        bump,
+    )]
+    /// CHECK: Handler registered for the payload kind. Empty if none is registered.
+    pub handler_registration: UncheckedAccount<'info>,
+
+    /// CHECK: Registered handler program, verified against `handler_registration`.
+    pub handler_program: Option<UncheckedAccount<'info>>,
+
     pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_SPONSOR_VAULT],
+        bump,
+    )]
+    /// Reimburses the payer for `received` rent for sponsored emitters.
+    pub sponsor_vault: Option<SystemAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [
+            Sponsorship::EMITTER_SEED_PREFIX,
+            &posted.emitter_chain().to_le_bytes()[..]
+        ],
+        bump,
+    )]
+    pub sponsorship: Option<Account<'info, Sponsorship>>,
 }
 
 #[derive(Accounts)]
@@ -218,7 +317,7 @@ pub struct CloseReceived<'info> {
     pub payer: Signer<'info>,
 
     #[account(
-        has_one = owner @ MessengerError::OwnerOnly,
+        constraint = config.is_owner(&owner.key()) @ MessengerError::OwnerOnly,
         seeds = [Config::SEED_PREFIX],
         bump
     )]
@@ -239,3 +338,617 @@ pub struct CloseReceived<'info> {
     )]
     pub received: Account<'info, Received>,
 }
+
+#[derive(Accounts)]
+#[instruction(program_id: Pubkey)]
+pub struct RegisterExecutionTarget<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ MessengerError::OwnerOnly,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [
+            ExecutionTarget::SEED_PREFIX,
+            program_id.as_ref()
+        ],
+        bump,
+        space = ExecutionTarget::MAXIMUM_SIZE
+    )]
+    pub execution_target: Account<'info, ExecutionTarget>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(program_id: Pubkey)]
+pub struct RemoveExecutionTarget<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ MessengerError::OwnerOnly,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            ExecutionTarget::SEED_PREFIX,
+            program_id.as_ref()
+        ],
+        bump
+    )]
+    pub execution_target: Account<'info, ExecutionTarget>,
+}
+
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32])]
+pub struct ExecuteMessage<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub wormhole_program: Program<'info, Wormhole>,
+
+    #[account(
+        seeds = [
+            wormhole::SEED_PREFIX_POSTED_VAA,
+            &vaa_hash
+        ],
+        bump,
+        seeds::program = wormhole_program.key
+    )]
+    pub posted: Account<'info, MessengerVaa>,
+
+    #[account(
+        seeds = [
+            ForeignEmitter::SEED_PREFIX,
+            &posted.emitter_chain().to_le_bytes()[..]
+        ],
+        bump,
+        constraint = foreign_emitter.verify(posted.emitter_chain(), posted.emitter_address()) @ MessengerError::InvalidForeignEmitter
+    )]
+    pub foreign_emitter: Account<'info, ForeignEmitter>,
+
+    #[account(
+        seeds = [
+            ExecutionTarget::SEED_PREFIX,
+            posted.data().execute_program_id().as_ref()
+        ],
+        bump,
+    )]
+    pub execution_target: Account<'info, ExecutionTarget>,
+
+    #[account(
+        executable,
+        address = execution_target.program_id @ MessengerError::InvalidExecutionTarget
+    )]
+    /// CHECK: Whitelisted program invoked on behalf of the remote signer.
+    pub target_program: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [
+            RemoteAccount::SEED_PREFIX,
+            &posted.emitter_chain().to_le_bytes()[..],
+            &posted.data().execute_sender()[..]
+        ],
+        bump,
+        space = RemoteAccount::MAXIMUM_SIZE
+    )]
+    pub remote_account: Account<'info, RemoteAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [
+            RemoteNonceBitmap::SEED_PREFIX,
+            &posted.emitter_chain().to_le_bytes()[..],
+            &posted.data().execute_sender()[..],
+            &app::replay_bitmap_index(posted.data().execute_nonce()).to_le_bytes()[..]
+        ],
+        bump,
+        space = RemoteNonceBitmap::MAXIMUM_SIZE
+    )]
+    /// Used nonces of the sender, covering the message's nonce.
+    pub remote_nonces: Box<Account<'info, RemoteNonceBitmap>>,
+
+    #[account(
+        mut,
+        seeds = [
+            RemoteAccount::SIGNER_SEED_PREFIX,
+            &posted.emitter_chain().to_le_bytes()[..],
+            &posted.data().execute_sender()[..]
+        ],
+        bump,
+    )]
+    /// CHECK: Data-less PDA that signs the remote instruction.
+    pub remote_signer: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FundSponsorVault<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ MessengerError::OwnerOnly,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_SPONSOR_VAULT],
+        bump,
+    )]
+    pub sponsor_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawSponsorVault<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ MessengerError::OwnerOnly,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_SPONSOR_VAULT],
+        bump,
+    )]
+    pub sponsor_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(caller: Pubkey)]
+pub struct SetCallerSponsorship<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ MessengerError::OwnerOnly,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [
+            Sponsorship::CALLER_SEED_PREFIX,
+            caller.as_ref()
+        ],
+        bump,
+        space = Sponsorship::MAXIMUM_SIZE
+    )]
+    pub sponsorship: Account<'info, Sponsorship>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(caller: Pubkey)]
+pub struct RemoveCallerSponsorship<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ MessengerError::OwnerOnly,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            Sponsorship::CALLER_SEED_PREFIX,
+            caller.as_ref()
+        ],
+        bump
+    )]
+    pub sponsorship: Account<'info, Sponsorship>,
+}
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct SetEmitterSponsorship<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ MessengerError::OwnerOnly,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [
+            Sponsorship::EMITTER_SEED_PREFIX,
+            &chain.to_le_bytes()[..]
+        ],
+        bump,
+        space = Sponsorship::MAXIMUM_SIZE
+    )]
+    pub sponsorship: Account<'info, Sponsorship>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct RemoveEmitterSponsorship<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ MessengerError::OwnerOnly,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            Sponsorship::EMITTER_SEED_PREFIX,
+            &chain.to_le_bytes()[..]
+        ],
+        bump
+    )]
+    pub sponsorship: Account<'info, Sponsorship>,
+}
+
+#[derive(Accounts)]
+#[instruction(kind: u8, program_id: Pubkey, accounts: Vec<HandlerAccount>)]
+pub struct RegisterHandler<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ MessengerError::OwnerOnly,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [
+            HandlerRegistration::SEED_PREFIX,
+            &[kind][..]
+        ],
+        bump,
+        space = HandlerRegistration::MAXIMUM_SIZE
+    )]
+    pub handler_registration: Account<'info, HandlerRegistration>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(kind: u8)]
+pub struct RemoveHandler<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ MessengerError::OwnerOnly,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            HandlerRegistration::SEED_PREFIX,
+            &[kind][..]
+        ],
+        bump
+    )]
+    pub handler_registration: Account<'info, HandlerRegistration>,
+}
+
+#[derive(Accounts)]
+pub struct SetAllowlistEnabled<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ MessengerError::OwnerOnly,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [SenderAllowlist::SEED_PREFIX],
+        bump,
+        space = SenderAllowlist::MAXIMUM_SIZE
+    )]
+    pub sender_allowlist: Account<'info, SenderAllowlist>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(sender: Pubkey)]
+pub struct SetAllowedSender<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ MessengerError::OwnerOnly,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [
+            AllowedSender::SEED_PREFIX,
+            sender.as_ref()
+        ],
+        bump,
+        space = AllowedSender::MAXIMUM_SIZE
+    )]
+    pub allowed_sender: Account<'info, AllowedSender>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(sender: Pubkey)]
+pub struct RemoveAllowedSender<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ MessengerError::OwnerOnly,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            AllowedSender::SEED_PREFIX,
+            sender.as_ref()
+        ],
+        bump
+    )]
+    pub allowed_sender: Account<'info, AllowedSender>,
+}
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct EnableBitmapReplay<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ MessengerError::OwnerOnly,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [
+            BitmapReplay::SEED_PREFIX,
+            &chain.to_le_bytes()[..]
+        ],
+        bump,
+        space = BitmapReplay::MAXIMUM_SIZE
+    )]
+    pub bitmap_replay: Account<'info, BitmapReplay>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct DisableBitmapReplay<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ MessengerError::OwnerOnly,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            BitmapReplay::SEED_PREFIX,
+            &chain.to_le_bytes()[..]
+        ],
+        bump
+    )]
+    pub bitmap_replay: Account<'info, BitmapReplay>,
+}
+
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32])]
+pub struct ConsumeMessage<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [
+            BitmapReplay::SEED_PREFIX,
+            &posted.emitter_chain().to_le_bytes()[..]
+        ],
+        bump,
+        constraint = !bitmap_replay.data_is_empty() @ MessengerError::BitmapReplayDisabled
+    )]
+    /// CHECK: Must exist, i.e. bitmap replay is on for the emitter chain.
+    pub bitmap_replay: UncheckedAccount<'info>,
+
+    pub wormhole_program: Program<'info, Wormhole>,
+
+    #[account(
+        seeds = [
+            wormhole::SEED_PREFIX_POSTED_VAA,
+            &vaa_hash
+        ],
+        bump,
+        seeds::program = wormhole_program.key
+    )]
+    pub posted: Account<'info, MessengerVaa>,
+
+    #[account(
+        seeds = [
+            ForeignEmitter::SEED_PREFIX,
+            &posted.emitter_chain().to_le_bytes()[..]
+        ],
+        bump,
+        constraint = foreign_emitter.verify(posted.emitter_chain(), posted.emitter_address()) @ MessengerError::InvalidForeignEmitter
+    )]
+    pub foreign_emitter: Account<'info, ForeignEmitter>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [
+            ReplayBitmap::SEED_PREFIX,
+            &posted.emitter_chain().to_le_bytes()[..],
+            &app::replay_bitmap_index(posted.sequence()).to_le_bytes()[..]
+        ],
+        bump,
+        space = ReplayBitmap::MAXIMUM_SIZE
+    )]
+    pub replay_bitmap: Box<Account<'info, ReplayBitmap>>,
+
+    #[account(
+        seeds = [
+            Received::SEED_PREFIX,
+            &posted.emitter_chain().to_le_bytes()[..],
+            &posted.sequence().to_le_bytes()[..]
+        ],
+        bump,
+        constraint = !app::is_consumed(&received) @ MessengerError::AlreadyReceived
+    )]
+    /// CHECK: Must be empty, i.e. the message was not stored by `receive_message`
+    /// while bitmap replay was off.
+    pub received: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            HandlerRegistration::SEED_PREFIX,
+            &[posted.data().message_kind()][..]
+        ],
+        bump,
+    )]
+    /// CHECK: Handler registered for the payload kind. Empty if none is registered.
+    pub handler_registration: UncheckedAccount<'info>,
+
+    /// CHECK: Registered handler program, verified against `handler_registration`.
+    pub handler_program: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(wormhole_message: Pubkey)]
+pub struct SetOutboxStatus<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.is_owner(&owner.key()) @ MessengerError::OwnerOnly,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+            OutboxRecord::SEED_PREFIX,
+            wormhole_message.as_ref()
+        ],
+        bump
+    )]
+    pub outbox_record: Account<'info, OutboxRecord>,
+}
+
+#[derive(Accounts)]
+#[instruction(wormhole_message: Pubkey)]
+pub struct CloseOutboxRecord<'info> {
+    pub sender: Signer<'info>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        has_one = sender @ MessengerError::NotRecordSender,
+        seeds = [
+            OutboxRecord::SEED_PREFIX,
+            wormhole_message.as_ref()
+        ],
+        bump
+    )]
+    pub outbox_record: Account<'info, OutboxRecord>,
+
+    #[account(
+        mut,
+        address = outbox_record.rent_payer
+    )]
+    /// CHECK: Payer of the record's rent, or the sponsor vault.
+    pub rent_payer: UncheckedAccount<'info>,
+}
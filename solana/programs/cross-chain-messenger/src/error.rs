@@ -28,4 +28,49 @@ pub enum MessengerError {
 
     #[msg("InvalidMessage")]
     InvalidMessage,
+
+    #[msg("InvalidExecutionTarget")]
+    InvalidExecutionTarget,
+
+    #[msg("InvalidRemoteNonce")]
+    InvalidRemoteNonce,
+
+    #[msg("InvalidRemoteAccounts")]
+    InvalidRemoteAccounts,
+
+    #[msg("InsufficientComputeUnits")]
+    InsufficientComputeUnits,
+
+    #[msg("InvalidSponsorship")]
+    InvalidSponsorship,
+
+    #[msg("SponsorshipLimitExceeded")]
+    SponsorshipLimitExceeded,
+
+    #[msg("SponsorVaultBelowRent")]
+    SponsorVaultBelowRent,
+
+    #[msg("InvalidHandler")]
+    InvalidHandler,
+
+    #[msg("InvalidHandlerAccounts")]
+    InvalidHandlerAccounts,
+
+    #[msg("SenderNotAllowed")]
+    SenderNotAllowed,
+
+    #[msg("SenderQuotaExceeded")]
+    SenderQuotaExceeded,
+
+    #[msg("BitmapReplayEnabled")]
+    BitmapReplayEnabled,
+
+    #[msg("BitmapReplayDisabled")]
+    BitmapReplayDisabled,
+
+    #[msg("AlreadyReceived")]
+    AlreadyReceived,
+
+    #[msg("NotRecordSender")]
+    NotRecordSender,
 }
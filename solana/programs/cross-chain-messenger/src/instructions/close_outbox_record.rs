@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+use crate::context::CloseOutboxRecord;
+
+pub fn handler(ctx: Context<CloseOutboxRecord>, _wormhole_message: Pubkey) -> Result<()> {
+    // Account closure is handled automatically by Anchor's close constraint
+    msg!(
+        "Outbox record {} closed",
+        ctx.accounts.outbox_record.sequence
+    );
+    Ok(())
+}
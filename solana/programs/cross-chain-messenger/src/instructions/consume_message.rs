@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use wormhole_anchor_sdk::app;
+
+use crate::{
+    context::ConsumeMessage,
+    error::MessengerError,
+    instructions::receive_message::{dispatch_to_handler, load_handler},
+    message::MessengerMessage,
+};
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ConsumeMessage<'info>>,
+    _vaa_hash: [u8; 32],
+) -> Result<()> {
+    let posted_message = &ctx.accounts.posted;
+
+    if let MessengerMessage::Message { payload } = posted_message.data() {
+        let registration =
+            load_handler(&ctx.accounts.handler_registration, ctx.remaining_accounts)?;
+
+        let chain = posted_message.emitter_chain();
+        let sequence = posted_message.sequence();
+
+        let replay_bitmap = &mut ctx.accounts.replay_bitmap;
+        replay_bitmap.chain = chain;
+        replay_bitmap.index = app::replay_bitmap_index(sequence);
+        require!(
+            app::consume_sequence(&mut replay_bitmap.bits, sequence),
+            MessengerError::AlreadyReceived
+        );
+
+        msg!("=== MESSAGE CONSUMED ===");
+        msg!("Emitter chain: {}", chain);
+        msg!("Sequence: {}", sequence);
+        msg!("Payload length: {} bytes", payload.len());
+        msg!("========================");
+
+        if let Some(registration) = registration {
+            dispatch_to_handler(
+                &registration,
+                ctx.accounts.handler_program.as_ref(),
+                &ctx.accounts.config.to_account_info(),
+                ctx.bumps.config,
+                ctx.remaining_accounts,
+                payload,
+            )?;
+        }
+
+        Ok(())
+    } else {
+        Err(MessengerError::InvalidMessage.into())
+    }
+}
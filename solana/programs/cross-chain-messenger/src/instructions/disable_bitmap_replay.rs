@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+use crate::context::DisableBitmapReplay;
+
+pub fn handler(_ctx: Context<DisableBitmapReplay>, chain: u16) -> Result<()> {
+    // Account closure is handled automatically by Anchor's close constraint
+    msg!("Bitmap replay protection disabled: chain={}", chain);
+    Ok(())
+}
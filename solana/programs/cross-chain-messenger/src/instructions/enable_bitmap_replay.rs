@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+use crate::context::EnableBitmapReplay;
+
+pub fn handler(ctx: Context<EnableBitmapReplay>, chain: u16) -> Result<()> {
+    ctx.accounts.bitmap_replay.chain = chain;
+
+    msg!("Bitmap replay protection enabled: chain={}", chain);
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{self, instruction::Instruction};
+use wormhole_anchor_sdk::app;
+
+use crate::{
+    context::ExecuteMessage,
+    error::MessengerError,
+    message::MessengerMessage,
+    state::RemoteAccount,
+};
+
+/// Compute units left in the current transaction. `anchor_lang` does not
+/// re-export `solana_program::compute_units`, so the syscall is declared here.
+#[cfg(target_os = "solana")]
+fn sol_remaining_compute_units() -> u64 {
+    extern "C" {
+        fn sol_remaining_compute_units() -> u64;
+    }
+    unsafe { sol_remaining_compute_units() }
+}
+
+/// Off-chain builds have no compute meter to check against.
+#[cfg(not(target_os = "solana"))]
+fn sol_remaining_compute_units() -> u64 {
+    u64::MAX
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteMessage<'info>>,
+    _vaa_hash: [u8; 32],
+) -> Result<()> {
+    let posted_message = &ctx.accounts.posted;
+
+    let MessengerMessage::Execute {
+        sender,
+        nonce,
+        compute_units,
+        program_id,
+        accounts,
+        data,
+    } = posted_message.data()
+    else {
+        return Err(MessengerError::InvalidMessage.into());
+    };
+
+    let emitter_chain = posted_message.emitter_chain();
+
+    // A freshly created remote account has no chain set yet.
+    let remote_account = &mut ctx.accounts.remote_account;
+    if remote_account.chain == 0 {
+        remote_account.chain = emitter_chain;
+        remote_account.sender = *sender;
+        remote_account.signer_bump = ctx.bumps.remote_signer;
+    }
+
+    // Each nonce can be used once, in any order, which also rejects replays
+    // of the VAA.
+    let remote_nonces = &mut ctx.accounts.remote_nonces;
+    remote_nonces.chain = emitter_chain;
+    remote_nonces.sender = *sender;
+    remote_nonces.index = app::replay_bitmap_index(*nonce);
+    require!(
+        app::consume_sequence(&mut remote_nonces.bits, *nonce),
+        MessengerError::InvalidRemoteNonce
+    );
+
+    // Make sure the relayer left enough compute for the remote instruction,
+    // so it can't be starved into failing on purpose.
+    require!(
+        sol_remaining_compute_units() >= u64::from(*compute_units),
+        MessengerError::InsufficientComputeUnits
+    );
+
+    // Only the remote signer may be marked as signer, and supplied accounts
+    // must match the metas carried in the message one to one.
+    let remote_signer = ctx.accounts.remote_signer.key();
+    require!(
+        ctx.remaining_accounts.len() == accounts.len(),
+        MessengerError::InvalidRemoteAccounts
+    );
+
+    let mut metas = Vec::with_capacity(accounts.len());
+    for (meta, info) in accounts.iter().zip(ctx.remaining_accounts.iter()) {
+        require!(
+            info.key() == meta.pubkey
+                && (!meta.is_signer || meta.pubkey == remote_signer)
+                && (!meta.is_writable || info.is_writable),
+            MessengerError::InvalidRemoteAccounts
+        );
+        metas.push(if meta.is_writable {
+            AccountMeta::new(meta.pubkey, meta.is_signer)
+        } else {
+            AccountMeta::new_readonly(meta.pubkey, meta.is_signer)
+        });
+    }
+
+    let mut account_infos = ctx.remaining_accounts.to_vec();
+    account_infos.push(ctx.accounts.remote_signer.to_account_info());
+    account_infos.push(ctx.accounts.target_program.to_account_info());
+
+    solana_program::program::invoke_signed(
+        &Instruction {
+            program_id: *program_id,
+            accounts: metas,
+            data: data.clone(),
+        },
+        &account_infos,
+        &[&[
+            RemoteAccount::SIGNER_SEED_PREFIX,
+            &emitter_chain.to_le_bytes()[..],
+            &sender[..],
+            &[ctx.accounts.remote_account.signer_bump],
+        ]],
+    )?;
+
+    msg!(
+        "Remote instruction executed: chain={}, nonce={}, program={}",
+        emitter_chain,
+        nonce,
+        program_id
+    );
+
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program;
+
+use crate::context::FundSponsorVault;
+
+pub fn handler(ctx: Context<FundSponsorVault>, amount: u64) -> Result<()> {
+    solana_program::program::invoke(
+        &solana_program::system_instruction::transfer(
+            &ctx.accounts.owner.key(),
+            &ctx.accounts.sponsor_vault.key(),
+            amount,
+        ),
+        &ctx.accounts.to_account_infos(),
+    )?;
+
+    msg!("Sponsor vault funded with {} lamports", amount);
+
+    Ok(())
+}
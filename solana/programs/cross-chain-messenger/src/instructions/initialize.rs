@@ -1,11 +1,8 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program;
-use wormhole_anchor_sdk::wormhole;
+use wormhole_anchor_sdk::{app, wormhole};
 
-use crate::{
-    context::{Initialize, SEED_PREFIX_SENT},
-    message::MessengerMessage,
-};
+use crate::{context::Initialize, message::MessengerMessage};
 
 pub fn handler(ctx: Context<Initialize>) -> Result<()> {
     let config = &mut ctx.accounts.config;
@@ -62,14 +59,7 @@ pub fn handler(ctx: Context<Initialize>) -> Result<()> {
                     rent: ctx.accounts.rent.to_account_info(),
                     system_program: ctx.accounts.system_program.to_account_info(),
                 },
-                &[
-                    &[
-                        SEED_PREFIX_SENT,
-                        &wormhole::INITIAL_SEQUENCE.to_le_bytes()[..],
-                        &[ctx.bumps.wormhole_message],
-                    ],
-                    &[wormhole::SEED_PREFIX_EMITTER, &[wormhole_emitter.bump]],
-                ],
+                &[&app::emitter_seeds(&[wormhole_emitter.bump])],
             ),
             config.batch_id,
             payload,
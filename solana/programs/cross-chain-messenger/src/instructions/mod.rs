@@ -3,3 +3,22 @@ pub mod register_emitter;
 pub mod send_message;
 pub mod receive_message;
 pub mod close_received;
+pub mod register_execution_target;
+pub mod remove_execution_target;
+pub mod execute_message;
+pub mod fund_sponsor_vault;
+pub mod withdraw_sponsor_vault;
+pub mod set_caller_sponsorship;
+pub mod set_emitter_sponsorship;
+pub mod remove_caller_sponsorship;
+pub mod remove_emitter_sponsorship;
+pub mod register_handler;
+pub mod remove_handler;
+pub mod set_allowlist_enabled;
+pub mod set_allowed_sender;
+pub mod remove_allowed_sender;
+pub mod enable_bitmap_replay;
+pub mod disable_bitmap_replay;
+pub mod consume_message;
+pub mod set_outbox_status;
+pub mod close_outbox_record;
@@ -1,13 +1,19 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{self, instruction::Instruction};
 
 use crate::{
-    context::ReceiveMessage,
+    context::{ReceiveMessage, SEED_PREFIX_SPONSOR_VAULT},
     error::MessengerError,
     message::MessengerMessage,
-    state::MESSAGE_MAX_LENGTH,
+    state::{
+        sponsor_vault_floor, Config, HandlerRegistration, ReplayBitmap, MESSAGE_MAX_LENGTH,
+    },
 };
 
-pub fn handler(ctx: Context<ReceiveMessage>, vaa_hash: [u8; 32]) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReceiveMessage<'info>>,
+    vaa_hash: [u8; 32],
+) -> Result<()> {
     let posted_message = &ctx.accounts.posted;
 
     if let MessengerMessage::Message { payload } = posted_message.data() {
@@ -15,6 +21,15 @@ pub fn handler(ctx: Context<ReceiveMessage>, vaa_hash: [u8; 32]) -> Result<()> {
             payload.len() <= MESSAGE_MAX_LENGTH,
             MessengerError::InvalidMessage,
         );
+        require!(
+            !ReplayBitmap::is_marked(&ctx.accounts.replay_bitmap, posted_message.sequence())?,
+            MessengerError::AlreadyReceived
+        );
+
+        // If a handler is registered for this payload kind, the supplied
+        // remaining accounts must match its layout before anything is stored.
+        let registration =
+            load_handler(&ctx.accounts.handler_registration, ctx.remaining_accounts)?;
 
         let received = &mut ctx.accounts.received;
         received.batch_id = posted_message.batch_id();
@@ -25,7 +40,7 @@ pub fn handler(ctx: Context<ReceiveMessage>, vaa_hash: [u8; 32]) -> Result<()> {
         msg!("Emitter chain: {}", posted_message.emitter_chain());
         msg!("Sequence: {}", posted_message.sequence());
         msg!("Payload length: {} bytes", payload.len());
-        
+
         if let Ok(text) = std::str::from_utf8(&payload) {
             msg!("Payload (text): {}", text);
         } else {
@@ -33,8 +48,122 @@ pub fn handler(ctx: Context<ReceiveMessage>, vaa_hash: [u8; 32]) -> Result<()> {
         }
         msg!("========================");
 
+        if let Some(registration) = registration {
+            dispatch_to_handler(
+                &registration,
+                ctx.accounts.handler_program.as_ref(),
+                &ctx.accounts.config.to_account_info(),
+                ctx.bumps.config,
+                ctx.remaining_accounts,
+                payload,
+            )?;
+        }
+
+        // Sponsored emitters have the Received rent refunded to the payer
+        // from the sponsor vault.
+        match (&ctx.accounts.sponsor_vault, &mut ctx.accounts.sponsorship) {
+            (Some(sponsor_vault), Some(sponsorship)) => {
+                let rent = ctx.accounts.received.to_account_info().lamports();
+                require!(
+                    sponsorship.charge(rent, Clock::get()?.unix_timestamp),
+                    MessengerError::SponsorshipLimitExceeded
+                );
+                require!(
+                    sponsor_vault.lamports().saturating_sub(rent) >= sponsor_vault_floor()?,
+                    MessengerError::SponsorVaultBelowRent
+                );
+
+                solana_program::program::invoke_signed(
+                    &solana_program::system_instruction::transfer(
+                        &sponsor_vault.key(),
+                        &ctx.accounts.payer.key(),
+                        rent,
+                    ),
+                    &[
+                        sponsor_vault.to_account_info(),
+                        ctx.accounts.payer.to_account_info(),
+                        ctx.accounts.system_program.to_account_info(),
+                    ],
+                    &[&[
+                        SEED_PREFIX_SPONSOR_VAULT,
+                        &[ctx.bumps.sponsor_vault.unwrap_or_default()],
+                    ]],
+                )?;
+
+                msg!("Sponsor vault refunded {} lamports of rent", rent);
+            }
+            (None, None) => {}
+            _ => return Err(MessengerError::InvalidSponsorship.into()),
+        }
+
         Ok(())
     } else {
         Err(MessengerError::InvalidMessage.into())
     }
 }
+
+/// Load the handler registered for a payload kind, if any, and check the
+/// remaining accounts against its account layout.
+pub(crate) fn load_handler(
+    handler_registration: &AccountInfo,
+    remaining_accounts: &[AccountInfo],
+) -> Result<Option<HandlerRegistration>> {
+    if handler_registration.data_is_empty() {
+        return Ok(None);
+    }
+
+    let registration =
+        HandlerRegistration::try_deserialize(&mut &handler_registration.try_borrow_data()?[..])?;
+    require!(
+        registration.verify_accounts(remaining_accounts),
+        MessengerError::InvalidHandlerAccounts
+    );
+
+    Ok(Some(registration))
+}
+
+/// Dispatch a payload to its registered handler, signed by the config PDA
+/// so the handler can tell the call came from the messenger.
+pub(crate) fn dispatch_to_handler<'info>(
+    registration: &HandlerRegistration,
+    handler_program: Option<&UncheckedAccount<'info>>,
+    config: &AccountInfo<'info>,
+    config_bump: u8,
+    remaining_accounts: &[AccountInfo<'info>],
+    payload: &[u8],
+) -> Result<()> {
+    let handler_program = handler_program
+        .filter(|program| program.key() == registration.program_id && program.executable)
+        .ok_or(MessengerError::InvalidHandler)?;
+
+    let mut accounts = vec![AccountMeta::new_readonly(config.key(), true)];
+    accounts.extend(remaining_accounts.iter().map(|info| {
+        if info.is_writable {
+            AccountMeta::new(info.key(), false)
+        } else {
+            AccountMeta::new_readonly(info.key(), false)
+        }
+    }));
+
+    let mut account_infos = vec![config.clone()];
+    account_infos.extend_from_slice(remaining_accounts);
+    account_infos.push(handler_program.to_account_info());
+
+    solana_program::program::invoke_signed(
+        &Instruction {
+            program_id: registration.program_id,
+            accounts,
+            data: payload.to_vec(),
+        },
+        &account_infos,
+        &[&[Config::SEED_PREFIX, &[config_bump]]],
+    )?;
+
+    msg!(
+        "Payload dispatched to handler {} (kind={})",
+        registration.program_id,
+        registration.kind
+    );
+
+    Ok(())
+}
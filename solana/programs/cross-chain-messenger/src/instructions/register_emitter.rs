@@ -1,21 +1,12 @@
 use anchor_lang::prelude::*;
-use wormhole_anchor_sdk::wormhole;
+use wormhole_anchor_sdk::app::ForeignPeer;
 
 use crate::{context::RegisterEmitter, error::MessengerError};
 
-pub fn handler(
-    ctx: Context<RegisterEmitter>,
-    chain: u16,
-    address: [u8; 32],
-) -> Result<()> {
-    require!(
-        chain > 0 && chain != wormhole::CHAIN_ID_SOLANA && !address.iter().all(|&x| x == 0),
-        MessengerError::InvalidForeignEmitter,
-    );
+pub fn handler(ctx: Context<RegisterEmitter>, chain: u16, address: [u8; 32]) -> Result<()> {
+    let peer = ForeignPeer::new(chain, address).ok_or(MessengerError::InvalidForeignEmitter)?;
 
-    let emitter = &mut ctx.accounts.foreign_emitter;
-    emitter.chain = chain;
-    emitter.address = address;
+    ctx.accounts.foreign_emitter.peer = peer;
 
     msg!(
         "Foreign emitter registered: chain={}, address={:?}",
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+use crate::{context::RegisterExecutionTarget, error::MessengerError};
+
+pub fn handler(ctx: Context<RegisterExecutionTarget>, program_id: Pubkey) -> Result<()> {
+    require!(
+        program_id != Pubkey::default() && program_id != crate::ID,
+        MessengerError::InvalidExecutionTarget,
+    );
+
+    ctx.accounts.execution_target.program_id = program_id;
+
+    msg!("Execution target registered: {}", program_id);
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    context::RegisterHandler,
+    error::MessengerError,
+    state::{HandlerAccount, HANDLER_MAX_ACCOUNTS},
+};
+
+pub fn handler(
+    ctx: Context<RegisterHandler>,
+    kind: u8,
+    program_id: Pubkey,
+    accounts: Vec<HandlerAccount>,
+) -> Result<()> {
+    require!(
+        program_id != Pubkey::default()
+            && program_id != crate::ID
+            && accounts.len() <= HANDLER_MAX_ACCOUNTS,
+        MessengerError::InvalidHandler,
+    );
+
+    let registration = &mut ctx.accounts.handler_registration;
+    registration.kind = kind;
+    registration.program_id = program_id;
+    registration.accounts = accounts;
+
+    msg!(
+        "Handler registered: kind={}, program={}, accounts={}",
+        kind,
+        program_id,
+        registration.accounts.len()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+use crate::context::RemoveAllowedSender;
+
+pub fn handler(_ctx: Context<RemoveAllowedSender>, sender: Pubkey) -> Result<()> {
+    // Account closure is handled automatically by Anchor's close constraint
+    msg!("Allowed sender removed: {}", sender);
+    Ok(())
+}
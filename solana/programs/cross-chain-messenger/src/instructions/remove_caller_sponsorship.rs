@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+use crate::context::RemoveCallerSponsorship;
+
+pub fn handler(_ctx: Context<RemoveCallerSponsorship>, caller: Pubkey) -> Result<()> {
+    // Account closure is handled automatically by Anchor's close constraint
+    msg!("Caller sponsorship removed: caller={}", caller);
+    Ok(())
+}
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+use crate::context::RemoveEmitterSponsorship;
+
+pub fn handler(_ctx: Context<RemoveEmitterSponsorship>, chain: u16) -> Result<()> {
+    // Account closure is handled automatically by Anchor's close constraint
+    msg!("Emitter sponsorship removed: chain={}", chain);
+    Ok(())
+}
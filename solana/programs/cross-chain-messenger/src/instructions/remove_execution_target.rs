@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+use crate::context::RemoveExecutionTarget;
+
+pub fn handler(_ctx: Context<RemoveExecutionTarget>, program_id: Pubkey) -> Result<()> {
+    // Account closure is handled automatically by Anchor's close constraint
+    msg!("Execution target removed: {}", program_id);
+    Ok(())
+}
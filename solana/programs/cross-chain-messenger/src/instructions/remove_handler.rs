@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+use crate::context::RemoveHandler;
+
+pub fn handler(_ctx: Context<RemoveHandler>, kind: u8) -> Result<()> {
+    // Account closure is handled automatically by Anchor's close constraint
+    msg!("Handler removed: kind={}", kind);
+    Ok(())
+}
@@ -1,30 +1,100 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program;
-use wormhole_anchor_sdk::wormhole;
+use wormhole_anchor_sdk::{app, wormhole};
 
 use crate::{
-    context::{SendMessage, SEED_PREFIX_SENT},
+    context::{SendMessage, SEED_PREFIX_SPONSOR_VAULT},
+    error::MessengerError,
     message::MessengerMessage,
+    state::{sponsor_vault_floor, OutboxStatus, SenderAllowlist},
 };
 
 pub fn handler(ctx: Context<SendMessage>, payload: Vec<u8>) -> Result<()> {
+    let sender = match &ctx.accounts.sender {
+        Some(sender) => sender.key(),
+        None => ctx.accounts.payer.key(),
+    };
+
+    if SenderAllowlist::is_enabled(&ctx.accounts.sender_allowlist)? {
+        let allowed_sender = ctx
+            .accounts
+            .allowed_sender
+            .as_mut()
+            .filter(|allowed_sender| allowed_sender.sender == sender)
+            .ok_or(MessengerError::SenderNotAllowed)?;
+        require!(
+            allowed_sender.record_send(ctx.accounts.clock.unix_timestamp),
+            MessengerError::SenderQuotaExceeded
+        );
+    }
+
+    // Sponsored callers pass both the vault and their sponsorship; the vault
+    // then covers the Wormhole fee, the message rent and the outbox record
+    // rent.
+    let sponsor_vault = match (&ctx.accounts.sponsor_vault, &ctx.accounts.sponsorship) {
+        (Some(vault), Some(_)) => Some(vault.to_account_info()),
+        (None, None) => None,
+        _ => return Err(MessengerError::InvalidSponsorship.into()),
+    };
+    let sponsor_vault_bump = [ctx.bumps.sponsor_vault.unwrap_or_default()];
+    let sponsor_vault_seeds = &[SEED_PREFIX_SPONSOR_VAULT.as_ref(), &sponsor_vault_bump[..]];
+    let mut payer_seeds: Vec<&[&[u8]]> = Vec::new();
+    if sponsor_vault.is_some() {
+        payer_seeds.push(&sponsor_vault_seeds[..]);
+    }
+
+    let payer = match &sponsor_vault {
+        Some(vault) => vault.clone(),
+        None => ctx.accounts.payer.to_account_info(),
+    };
+    let payer_lamports_before = payer.lamports();
+
     let fee = ctx.accounts.wormhole_bridge.fee();
     if fee > 0 {
-        solana_program::program::invoke(
+        solana_program::program::invoke_signed(
             &solana_program::system_instruction::transfer(
-                &ctx.accounts.payer.key(),
+                &payer.key(),
                 &ctx.accounts.wormhole_fee_collector.key(),
                 fee,
             ),
             &ctx.accounts.to_account_infos(),
+            &payer_seeds,
         )?;
     }
 
+    // The payer funds the outbox record when it is created; the vault refunds
+    // that rent, and gets it back when the record is closed.
+    if sponsor_vault.is_some() {
+        solana_program::program::invoke_signed(
+            &solana_program::system_instruction::transfer(
+                &payer.key(),
+                &ctx.accounts.payer.key(),
+                ctx.accounts.outbox_record.to_account_info().lamports(),
+            ),
+            &ctx.accounts.to_account_infos(),
+            &payer_seeds,
+        )?;
+    }
+
+    // Wormhole assigns the tracker's current value to the posted message.
+    let outbox_record = &mut ctx.accounts.outbox_record;
+    outbox_record.sender = sender;
+    outbox_record.sequence = ctx.accounts.wormhole_sequence.value();
+    outbox_record.batch_id = ctx.accounts.config.batch_id;
+    outbox_record.timestamp = ctx.accounts.clock.unix_timestamp;
+    outbox_record.status = OutboxStatus::Sent;
+    outbox_record.rent_payer = payer.key();
+
     let wormhole_emitter = &ctx.accounts.wormhole_emitter;
     let config = &ctx.accounts.config;
 
     let encoded_payload: Vec<u8> = MessengerMessage::Message { payload }.try_to_vec()?;
 
+    let emitter_bump = [wormhole_emitter.bump];
+    let emitter_seeds = app::emitter_seeds(&emitter_bump);
+    let mut signer_seeds = vec![&emitter_seeds[..]];
+    signer_seeds.extend_from_slice(&payer_seeds);
+
     wormhole::post_message(
         CpiContext::new_with_signer(
             ctx.accounts.wormhole_program.to_account_info(),
@@ -33,26 +103,33 @@ pub fn handler(ctx: Context<SendMessage>, payload: Vec<u8>) -> Result<()> {
                 message: ctx.accounts.wormhole_message.to_account_info(),
                 emitter: wormhole_emitter.to_account_info(),
                 sequence: ctx.accounts.wormhole_sequence.to_account_info(),
-                payer: ctx.accounts.payer.to_account_info(),
+                payer: payer.clone(),
                 fee_collector: ctx.accounts.wormhole_fee_collector.to_account_info(),
                 clock: ctx.accounts.clock.to_account_info(),
                 rent: ctx.accounts.rent.to_account_info(),
                 system_program: ctx.accounts.system_program.to_account_info(),
             },
-            &[
-                &[
-                    SEED_PREFIX_SENT,
-                    &ctx.accounts.wormhole_sequence.next_value().to_le_bytes()[..],
-                    &[ctx.bumps.wormhole_message],
-                ],
-                &[wormhole::SEED_PREFIX_EMITTER, &[wormhole_emitter.bump]],
-            ],
+            &signer_seeds,
         ),
         config.batch_id,
         encoded_payload,
         config.finality.try_into().unwrap(),
     )?;
 
+    if let Some(sponsorship) = &mut ctx.accounts.sponsorship {
+        let spent = payer_lamports_before.saturating_sub(payer.lamports());
+        require!(
+            sponsorship.charge(spent, ctx.accounts.clock.unix_timestamp),
+            MessengerError::SponsorshipLimitExceeded
+        );
+        require!(
+            payer.lamports() >= sponsor_vault_floor()?,
+            MessengerError::SponsorVaultBelowRent
+        );
+
+        msg!("Sponsor vault paid {} lamports", spent);
+    }
+
     msg!("Message sent via Wormhole");
 
     Ok(())
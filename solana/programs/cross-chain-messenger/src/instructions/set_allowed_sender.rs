@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::context::SetAllowedSender;
+
+pub fn handler(
+    ctx: Context<SetAllowedSender>,
+    sender: Pubkey,
+    quota: u64,
+    period: i64,
+) -> Result<()> {
+    let allowed_sender = &mut ctx.accounts.allowed_sender;
+    allowed_sender.sender = sender;
+    allowed_sender.quota = quota;
+    if allowed_sender.period != period {
+        allowed_sender.period = period;
+        allowed_sender.period_start = Clock::get()?.unix_timestamp;
+    }
+
+    msg!(
+        "Allowed sender set: sender={}, quota={}, period={}, sent={}",
+        sender,
+        quota,
+        period,
+        allowed_sender.sent
+    );
+
+    Ok(())
+}
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+use crate::context::SetAllowlistEnabled;
+
+pub fn handler(ctx: Context<SetAllowlistEnabled>, enabled: bool) -> Result<()> {
+    ctx.accounts.sender_allowlist.enabled = enabled;
+
+    msg!("Sender allowlist enabled: {}", enabled);
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::context::SetCallerSponsorship;
+
+pub fn handler(
+    ctx: Context<SetCallerSponsorship>,
+    caller: Pubkey,
+    spending_limit: u64,
+    period: i64,
+) -> Result<()> {
+    // (Re)setting a sponsorship starts a fresh period.
+    let sponsorship = &mut ctx.accounts.sponsorship;
+    sponsorship.spending_limit = spending_limit;
+    sponsorship.spent = 0;
+    sponsorship.period = period;
+    sponsorship.period_start = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Caller sponsorship set: caller={}, spending_limit={}, period={}",
+        caller,
+        spending_limit,
+        period
+    );
+
+    Ok(())
+}
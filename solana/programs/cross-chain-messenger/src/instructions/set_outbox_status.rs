@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::{context::SetOutboxStatus, state::OutboxStatus};
+
+pub fn handler(
+    ctx: Context<SetOutboxStatus>,
+    _wormhole_message: Pubkey,
+    status: OutboxStatus,
+) -> Result<()> {
+    let outbox_record = &mut ctx.accounts.outbox_record;
+    outbox_record.status = status;
+
+    msg!(
+        "Message {} status updated: {:?}",
+        outbox_record.sequence,
+        status
+    );
+    Ok(())
+}
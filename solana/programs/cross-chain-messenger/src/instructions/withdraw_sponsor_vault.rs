@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program;
+
+use crate::{
+    context::{WithdrawSponsorVault, SEED_PREFIX_SPONSOR_VAULT},
+    error::MessengerError,
+    state::sponsor_vault_floor,
+};
+
+pub fn handler(ctx: Context<WithdrawSponsorVault>, amount: u64) -> Result<()> {
+    // The vault must stay rent exempt, or the runtime rejects later debits.
+    require!(
+        ctx.accounts.sponsor_vault.lamports().saturating_sub(amount) >= sponsor_vault_floor()?,
+        MessengerError::SponsorVaultBelowRent
+    );
+
+    solana_program::program::invoke_signed(
+        &solana_program::system_instruction::transfer(
+            &ctx.accounts.sponsor_vault.key(),
+            &ctx.accounts.owner.key(),
+            amount,
+        ),
+        &ctx.accounts.to_account_infos(),
+        &[&[SEED_PREFIX_SPONSOR_VAULT, &[ctx.bumps.sponsor_vault]]],
+    )?;
+
+    msg!("Withdrew {} lamports from sponsor vault", amount);
+
+    Ok(())
+}
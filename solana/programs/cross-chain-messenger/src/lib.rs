@@ -35,6 +35,7 @@ pub mod cross_chain_messenger {
     /// Send a message to another chain via Wormhole.
     /// The message is encoded with a payload ID and sent through Wormhole Core.
     /// Guardians will attest the message, which can then be received on the target chain.
+    /// An outbox record of the message is created alongside it.
     pub fn send_message(ctx: Context<SendMessage>, payload: Vec<u8>) -> Result<()> {
         instructions::send_message::handler(ctx, payload)
     }
@@ -42,7 +43,12 @@ pub mod cross_chain_messenger {
     /// Receive and verify a message from another chain.
     /// The VAA must be verified by Wormhole before calling this instruction.
     /// The message is stored in a Received account for later processing.
-    pub fn receive_message(ctx: Context<ReceiveMessage>, vaa_hash: [u8; 32]) -> Result<()> {
+    /// If a handler is registered for the payload kind, the remaining accounts
+    /// are checked against its layout and the payload is dispatched to it.
+    pub fn receive_message<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReceiveMessage<'info>>,
+        vaa_hash: [u8; 32],
+    ) -> Result<()> {
         instructions::receive_message::handler(ctx, vaa_hash)
     }
 
@@ -55,4 +61,173 @@ pub mod cross_chain_messenger {
     ) -> Result<()> {
         instructions::close_received::handler(ctx, emitter_chain, sequence)
     }
+
+    /// Whitelist a program that remotely-controlled signers may invoke.
+    /// Only the program owner can call this instruction.
+    pub fn register_execution_target(
+        ctx: Context<RegisterExecutionTarget>,
+        program_id: Pubkey,
+    ) -> Result<()> {
+        instructions::register_execution_target::handler(ctx, program_id)
+    }
+
+    /// Remove a program from the execution whitelist.
+    /// Only the program owner can call this instruction.
+    pub fn remove_execution_target(
+        ctx: Context<RemoveExecutionTarget>,
+        program_id: Pubkey,
+    ) -> Result<()> {
+        instructions::remove_execution_target::handler(ctx, program_id)
+    }
+
+    /// Execute an instruction carried by an `Execute` message.
+    /// The instruction is signed by a PDA derived from the emitter chain and
+    /// the source-chain sender, and its accounts are passed as remaining accounts.
+    /// Each nonce of a sender executes once, in any order.
+    pub fn execute_message<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteMessage<'info>>,
+        vaa_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::execute_message::handler(ctx, vaa_hash)
+    }
+
+    /// Deposit lamports into the sponsor vault.
+    /// Only the program owner can call this instruction.
+    pub fn fund_sponsor_vault(ctx: Context<FundSponsorVault>, amount: u64) -> Result<()> {
+        instructions::fund_sponsor_vault::handler(ctx, amount)
+    }
+
+    /// Withdraw lamports from the sponsor vault back to the owner.
+    /// Only the program owner can call this instruction.
+    pub fn withdraw_sponsor_vault(ctx: Context<WithdrawSponsorVault>, amount: u64) -> Result<()> {
+        instructions::withdraw_sponsor_vault::handler(ctx, amount)
+    }
+
+    /// Sponsor `send_message` fees and rent for a caller, up to a lamport
+    /// limit per `period` seconds (zero for a lifetime limit).
+    /// Only the program owner can call this instruction.
+    pub fn set_caller_sponsorship(
+        ctx: Context<SetCallerSponsorship>,
+        caller: Pubkey,
+        spending_limit: u64,
+        period: i64,
+    ) -> Result<()> {
+        instructions::set_caller_sponsorship::handler(ctx, caller, spending_limit, period)
+    }
+
+    /// Stop sponsoring a caller.
+    /// Only the program owner can call this instruction.
+    pub fn remove_caller_sponsorship(
+        ctx: Context<RemoveCallerSponsorship>,
+        caller: Pubkey,
+    ) -> Result<()> {
+        instructions::remove_caller_sponsorship::handler(ctx, caller)
+    }
+
+    /// Sponsor `receive_message` rent for a foreign emitter, up to a lamport
+    /// limit per `period` seconds (zero for a lifetime limit).
+    /// Only the program owner can call this instruction.
+    pub fn set_emitter_sponsorship(
+        ctx: Context<SetEmitterSponsorship>,
+        chain: u16,
+        spending_limit: u64,
+        period: i64,
+    ) -> Result<()> {
+        instructions::set_emitter_sponsorship::handler(ctx, chain, spending_limit, period)
+    }
+
+    /// Stop sponsoring a foreign emitter.
+    /// Only the program owner can call this instruction.
+    pub fn remove_emitter_sponsorship(
+        ctx: Context<RemoveEmitterSponsorship>,
+        chain: u16,
+    ) -> Result<()> {
+        instructions::remove_emitter_sponsorship::handler(ctx, chain)
+    }
+
+    /// Register a handler program and account layout for a payload kind
+    /// (the first byte of a message payload).
+    /// Only the program owner can call this instruction.
+    pub fn register_handler(
+        ctx: Context<RegisterHandler>,
+        kind: u8,
+        program_id: Pubkey,
+        accounts: Vec<HandlerAccount>,
+    ) -> Result<()> {
+        instructions::register_handler::handler(ctx, kind, program_id, accounts)
+    }
+
+    /// Remove the handler registered for a payload kind.
+    /// Only the program owner can call this instruction.
+    pub fn remove_handler(ctx: Context<RemoveHandler>, kind: u8) -> Result<()> {
+        instructions::remove_handler::handler(ctx, kind)
+    }
+
+    /// Enable or disable the sender allowlist for `send_message`.
+    /// Only the program owner can call this instruction.
+    pub fn set_allowlist_enabled(ctx: Context<SetAllowlistEnabled>, enabled: bool) -> Result<()> {
+        instructions::set_allowlist_enabled::handler(ctx, enabled)
+    }
+
+    /// Allow a signer (wallet or calling program PDA) to send up to `quota`
+    /// messages per `period` seconds (zero for a lifetime quota).
+    /// Only the program owner can call this instruction.
+    pub fn set_allowed_sender(
+        ctx: Context<SetAllowedSender>,
+        sender: Pubkey,
+        quota: u64,
+        period: i64,
+    ) -> Result<()> {
+        instructions::set_allowed_sender::handler(ctx, sender, quota, period)
+    }
+
+    /// Remove a sender from the allowlist.
+    /// Only the program owner can call this instruction.
+    pub fn remove_allowed_sender(ctx: Context<RemoveAllowedSender>, sender: Pubkey) -> Result<()> {
+        instructions::remove_allowed_sender::handler(ctx, sender)
+    }
+
+    /// Switch a foreign emitter chain to bitmap replay protection: its
+    /// messages are consumed with `consume_message` instead of `receive_message`.
+    /// Only the program owner can call this instruction.
+    pub fn enable_bitmap_replay(ctx: Context<EnableBitmapReplay>, chain: u16) -> Result<()> {
+        instructions::enable_bitmap_replay::handler(ctx, chain)
+    }
+
+    /// Switch a foreign emitter chain back to `receive_message`. Sequences
+    /// already consumed stay marked and cannot be received again.
+    /// Only the program owner can call this instruction.
+    pub fn disable_bitmap_replay(ctx: Context<DisableBitmapReplay>, chain: u16) -> Result<()> {
+        instructions::disable_bitmap_replay::handler(ctx, chain)
+    }
+
+    /// Process a message without storing it: its sequence is marked in the
+    /// emitter chain's replay bitmap and the payload is handed to its
+    /// registered handler, if any.
+    pub fn consume_message<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ConsumeMessage<'info>>,
+        vaa_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::consume_message::handler(ctx, vaa_hash)
+    }
+
+    /// Update the status of a sent message's outbox record.
+    /// Only the program owner can call this instruction.
+    pub fn set_outbox_status(
+        ctx: Context<SetOutboxStatus>,
+        wormhole_message: Pubkey,
+        status: OutboxStatus,
+    ) -> Result<()> {
+        instructions::set_outbox_status::handler(ctx, wormhole_message, status)
+    }
+
+    /// Close a sent message's outbox record and return its rent to whoever
+    /// paid it: the payer, or the sponsor vault for a sponsored send.
+    /// Only the message's sender can call this instruction.
+    pub fn close_outbox_record(
+        ctx: Context<CloseOutboxRecord>,
+        wormhole_message: Pubkey,
+    ) -> Result<()> {
+        instructions::close_outbox_record::handler(ctx, wormhole_message)
+    }
 }
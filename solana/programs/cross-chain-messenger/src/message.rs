@@ -4,17 +4,75 @@ use wormhole_io::Readable;
 
 const PAYLOAD_ID_ALIVE: u8 = 0;
 const PAYLOAD_ID_MESSAGE: u8 = 1;
+const PAYLOAD_ID_EXECUTE: u8 = 2;
 
 pub const MESSAGE_MAX_LENGTH: usize = 1024;
+pub const EXECUTE_MAX_ACCOUNTS: usize = 32;
 
-#[derive(Clone)]
+/// Account meta of a remotely-controlled instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RemoteAccountMeta {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
 /// Message types for the messenger program.
 /// 
 /// * `Alive`: Payload ID == 0. Emitted during initialization.
 /// * `Message`: Payload ID == 1. Arbitrary payload data.
+/// * `Execute`: Payload ID == 2. Instruction to execute on behalf of a
+///   source-chain sender via its remote signer PDA.
 pub enum MessengerMessage {
     Alive { program_id: Pubkey },
     Message { payload: Vec<u8> },
+    Execute {
+        sender: [u8; 32],
+        nonce: u64,
+        compute_units: u32,
+        program_id: Pubkey,
+        accounts: Vec<RemoteAccountMeta>,
+        data: Vec<u8>,
+    },
+}
+
+impl MessengerMessage {
+    /// Kind of a `Message` payload, i.e. its first byte (zero otherwise).
+    pub fn message_kind(&self) -> u8 {
+        match self {
+            MessengerMessage::Message { payload } => payload.first().copied().unwrap_or_default(),
+            _ => 0,
+        }
+    }
+
+    /// Source-chain sender of an `Execute` message (zero otherwise).
+    pub fn execute_sender(&self) -> [u8; 32] {
+        match self {
+            MessengerMessage::Execute { sender, .. } => *sender,
+            _ => [0; 32],
+        }
+    }
+
+    /// Nonce of an `Execute` message (zero otherwise).
+    pub fn execute_nonce(&self) -> u64 {
+        match self {
+            MessengerMessage::Execute { nonce, .. } => *nonce,
+            _ => 0,
+        }
+    }
+
+    /// Target program of an `Execute` message (default otherwise).
+    pub fn execute_program_id(&self) -> Pubkey {
+        match self {
+            MessengerMessage::Execute { program_id, .. } => *program_id,
+            _ => Pubkey::default(),
+        }
+    }
+}
+
+fn invalid_input(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, msg)
 }
 
 impl AnchorSerialize for MessengerMessage {
@@ -39,6 +97,41 @@ impl AnchorSerialize for MessengerMessage {
                     Ok(())
                 }
             }
+            MessengerMessage::Execute {
+                sender,
+                nonce,
+                compute_units,
+                program_id,
+                accounts,
+                data,
+            } => {
+                if accounts.len() > EXECUTE_MAX_ACCOUNTS {
+                    return Err(invalid_input(format!(
+                        "accounts exceed {EXECUTE_MAX_ACCOUNTS} entries"
+                    )));
+                }
+                if data.len() > MESSAGE_MAX_LENGTH {
+                    return Err(invalid_input(format!(
+                        "data exceeds {MESSAGE_MAX_LENGTH} bytes"
+                    )));
+                }
+                PAYLOAD_ID_EXECUTE.serialize(writer)?;
+                sender.serialize(writer)?;
+                nonce.to_be_bytes().serialize(writer)?;
+                compute_units.to_be_bytes().serialize(writer)?;
+                program_id.serialize(writer)?;
+                (accounts.len() as u8).serialize(writer)?;
+                for meta in accounts {
+                    meta.pubkey.serialize(writer)?;
+                    meta.is_signer.serialize(writer)?;
+                    meta.is_writable.serialize(writer)?;
+                }
+                (data.len() as u16).to_be_bytes().serialize(writer)?;
+                for item in data {
+                    item.serialize(writer)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -62,6 +155,45 @@ impl AnchorDeserialize for MessengerMessage {
                     Ok(MessengerMessage::Message { payload: buf })
                 }
             }
+            PAYLOAD_ID_EXECUTE => {
+                let sender = <[u8; 32]>::read(reader)?;
+                let nonce = u64::read(reader)?;
+                let compute_units = u32::read(reader)?;
+                let program_id = Pubkey::from(<[u8; 32]>::read(reader)?);
+
+                let num_accounts = u8::read(reader)? as usize;
+                if num_accounts > EXECUTE_MAX_ACCOUNTS {
+                    return Err(invalid_input(format!(
+                        "accounts exceed {EXECUTE_MAX_ACCOUNTS} entries"
+                    )));
+                }
+                let mut accounts = Vec::with_capacity(num_accounts);
+                for _ in 0..num_accounts {
+                    accounts.push(RemoteAccountMeta {
+                        pubkey: Pubkey::from(<[u8; 32]>::read(reader)?),
+                        is_signer: u8::read(reader)? != 0,
+                        is_writable: u8::read(reader)? != 0,
+                    });
+                }
+
+                let length = u16::read(reader)? as usize;
+                if length > MESSAGE_MAX_LENGTH {
+                    return Err(invalid_input(format!(
+                        "data exceeds {MESSAGE_MAX_LENGTH} bytes"
+                    )));
+                }
+                let mut data = vec![0; length];
+                reader.read_exact(&mut data)?;
+
+                Ok(MessengerMessage::Execute {
+                    sender,
+                    nonce,
+                    compute_units,
+                    program_id,
+                    accounts,
+                    data,
+                })
+            }
             _ => Err(io::Error::new(
                 io::ErrorKind::InvalidInput,
                 "invalid payload ID",
@@ -69,3 +201,86 @@ impl AnchorDeserialize for MessengerMessage {
         }
     }
 }
+
+#[cfg(test)]
+fn execute_message(num_accounts: usize, data_len: usize) -> MessengerMessage {
+    MessengerMessage::Execute {
+        sender: [7; 32],
+        nonce: 42,
+        compute_units: 200_000,
+        program_id: Pubkey::new_unique(),
+        accounts: (0..num_accounts)
+            .map(|i| RemoteAccountMeta {
+                pubkey: Pubkey::new_unique(),
+                is_signer: i % 2 == 0,
+                is_writable: i % 3 == 0,
+            })
+            .collect(),
+        data: vec![0xab; data_len],
+    }
+}
+
+#[test]
+fn execute_roundtrip_serialization() {
+    let original = execute_message(EXECUTE_MAX_ACCOUNTS, MESSAGE_MAX_LENGTH);
+
+    let deserialized = MessengerMessage::deserialize(
+        &mut original
+            .try_to_vec()
+            .expect("Serialization should work")
+            .as_ref(),
+    )
+    .expect("Deserialization should work");
+
+    assert_eq!(original, deserialized);
+}
+
+#[test]
+fn execute_rejects_oversized_fields() {
+    assert!(execute_message(EXECUTE_MAX_ACCOUNTS + 1, 0)
+        .try_to_vec()
+        .is_err());
+    assert!(execute_message(0, MESSAGE_MAX_LENGTH + 1)
+        .try_to_vec()
+        .is_err());
+
+    // A hand-built payload declaring too many accounts must not decode.
+    let mut encoded = execute_message(0, 0).try_to_vec().unwrap();
+    encoded[1 + 32 + 8 + 4 + 32] = (EXECUTE_MAX_ACCOUNTS + 1) as u8;
+    assert!(MessengerMessage::deserialize(&mut encoded.as_ref()).is_err());
+}
+
+/// Same bytes as `test_ExecuteGoldenVector` in the EVM messenger tests.
+#[test]
+fn execute_golden_vector() {
+    let message = MessengerMessage::Execute {
+        sender: [0x11; 32],
+        nonce: 42,
+        compute_units: 200_000,
+        program_id: Pubkey::from([0x22; 32]),
+        accounts: vec![RemoteAccountMeta {
+            pubkey: Pubkey::from([0x33; 32]),
+            is_signer: true,
+            is_writable: false,
+        }],
+        data: vec![0xde, 0xad, 0xbe, 0xef],
+    };
+
+    let mut expected = vec![2];
+    expected.extend([0x11; 32]);
+    expected.extend(42u64.to_be_bytes());
+    expected.extend(200_000u32.to_be_bytes());
+    expected.extend([0x22; 32]);
+    expected.push(1);
+    expected.extend([0x33; 32]);
+    expected.extend([1, 0]);
+    expected.extend([0, 4, 0xde, 0xad, 0xbe, 0xef]);
+
+    let encoded = message.try_to_vec().unwrap();
+    assert_eq!(encoded, expected);
+    assert_eq!(encoded.len(), 118);
+    assert_eq!(
+        MessengerMessage::deserialize(&mut encoded.as_ref()).unwrap(),
+        message
+    );
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+#[allow(unused_imports)]
+use crate::ID;
+
+/// Signer (wallet or calling program PDA) allowed to send messages while
+/// the allowlist is enabled, with the number of messages it may send per
+/// period of `period` seconds. A zero period never resets `sent`.
+#[account]
+#[derive(Default)]
+pub struct AllowedSender {
+    pub sender: Pubkey,
+    pub quota: u64,
+    pub sent: u64,
+    pub period: i64,
+    pub period_start: i64,
+}
+
+impl AllowedSender {
+    pub const MAXIMUM_SIZE: usize = 8 // discriminator
+        + 32 // sender
+        + 8 // quota
+        + 8 // sent
+        + 8 // period
+        + 8 // period_start
+    ;
+    pub const SEED_PREFIX: &'static [u8; 14] = b"allowed_sender";
+
+    /// Count one sent message, starting a new period first if the current
+    /// one has elapsed. Returns false if the quota is used up.
+    pub fn record_send(&mut self, now: i64) -> bool {
+        if self.period > 0 && now.saturating_sub(self.period_start) >= self.period {
+            self.sent = 0;
+            self.period_start = now;
+        }
+        if self.sent >= self.quota {
+            return false;
+        }
+        self.sent += 1;
+        true
+    }
+}
+
+#[test]
+fn allowed_sender_quota_resets_each_period() {
+    let mut allowed_sender = AllowedSender {
+        quota: 2,
+        period: 100,
+        ..Default::default()
+    };
+
+    assert!(allowed_sender.record_send(0));
+    assert!(allowed_sender.record_send(50));
+    assert!(!allowed_sender.record_send(99));
+    assert!(allowed_sender.record_send(100));
+    assert_eq!(allowed_sender.sent, 1);
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+#[allow(unused_imports)]
+use crate::ID;
+
+/// Marker that messages from a foreign emitter chain are consumed with
+/// `consume_message` against replay bitmaps instead of being stored by
+/// `receive_message`. Closing it switches the chain back.
+#[account]
+#[derive(Default)]
+pub struct BitmapReplay {
+    pub chain: u16,
+}
+
+impl BitmapReplay {
+    pub const MAXIMUM_SIZE: usize = 8 // discriminator
+        + 2 // chain
+    ;
+    pub const SEED_PREFIX: &'static [u8; 13] = b"bitmap_replay";
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use wormhole_anchor_sdk::app::WormholeApp;
 
 #[allow(unused_imports)]
 use crate::ID;
@@ -32,3 +33,9 @@ impl Config {
     ;
     pub const SEED_PREFIX: &'static [u8; 6] = b"config";
 }
+
+impl WormholeApp for Config {
+    fn owner(&self) -> &Pubkey {
+        &self.owner
+    }
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+#[allow(unused_imports)]
+use crate::ID;
+
+/// Program that remotely-controlled signers are allowed to invoke.
+#[account]
+#[derive(Default)]
+pub struct ExecutionTarget {
+    pub program_id: Pubkey,
+}
+
+impl ExecutionTarget {
+    pub const MAXIMUM_SIZE: usize = 8 // discriminator
+        + 32 // program_id
+    ;
+    pub const SEED_PREFIX: &'static [u8; 16] = b"execution_target";
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use wormhole_anchor_sdk::app::{ForeignPeer, WormholePeer};
 
 #[allow(unused_imports)]
 use crate::ID;
@@ -6,18 +7,22 @@ use crate::ID;
 #[account]
 #[derive(Default)]
 pub struct ForeignEmitter {
-    pub chain: u16,
-    pub address: [u8; 32],
+    pub peer: ForeignPeer,
 }
 
 impl ForeignEmitter {
     pub const MAXIMUM_SIZE: usize = 8 // discriminator
-        + 2 // chain
-        + 32 // address
+        + ForeignPeer::LEN // peer
     ;
     pub const SEED_PREFIX: &'static [u8; 15] = b"foreign_emitter";
+}
+
+impl WormholePeer for ForeignEmitter {
+    fn chain(&self) -> u16 {
+        self.peer.chain
+    }
 
-    pub fn verify(&self, address: &[u8; 32]) -> bool {
-        *address == self.address
+    fn address(&self) -> &[u8; 32] {
+        &self.peer.address
     }
 }
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+#[allow(unused_imports)]
+use crate::ID;
+
+pub const HANDLER_MAX_ACCOUNTS: usize = 16;
+
+/// Expected account in a handler's account layout. A default `address`
+/// accepts any account in that position.
+#[derive(Default, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct HandlerAccount {
+    pub address: Pubkey,
+    pub is_writable: bool,
+}
+
+impl HandlerAccount {
+    pub const LEN: usize = 32 + 1;
+}
+
+/// Handler program registered for a payload kind (first payload byte),
+/// together with the account layout it requires.
+#[account]
+#[derive(Default)]
+pub struct HandlerRegistration {
+    pub kind: u8,
+    pub program_id: Pubkey,
+    pub accounts: Vec<HandlerAccount>,
+}
+
+impl HandlerRegistration {
+    pub const MAXIMUM_SIZE: usize = 8 // discriminator
+        + 1 // kind
+        + 32 // program_id
+        + 4 // Vec length
+        + HANDLER_MAX_ACCOUNTS * HandlerAccount::LEN // accounts
+    ;
+    pub const SEED_PREFIX: &'static [u8; 7] = b"handler";
+
+    /// Check that `accounts` match the registered layout position by position.
+    pub fn verify_accounts(&self, accounts: &[AccountInfo]) -> bool {
+        accounts.len() == self.accounts.len()
+            && self.accounts.iter().zip(accounts).all(|(expected, info)| {
+                (expected.address == Pubkey::default() || expected.address == info.key())
+                    && expected.is_writable == info.is_writable
+            })
+    }
+}
@@ -1,9 +1,29 @@
+pub use allowed_sender::*;
+pub use bitmap_replay::*;
 pub use config::*;
+pub use execution_target::*;
 pub use foreign_emitter::*;
+pub use handler_registration::*;
+pub use outbox_record::*;
 pub use received::*;
+pub use remote_account::*;
+pub use remote_nonce_bitmap::*;
+pub use replay_bitmap::*;
+pub use sender_allowlist::*;
+pub use sponsorship::*;
 pub use wormhole_emitter::*;
 
+pub mod allowed_sender;
+pub mod bitmap_replay;
 pub mod config;
+pub mod execution_target;
 pub mod foreign_emitter;
+pub mod handler_registration;
+pub mod outbox_record;
 pub mod received;
+pub mod remote_account;
+pub mod remote_nonce_bitmap;
+pub mod replay_bitmap;
+pub mod sender_allowlist;
+pub mod sponsorship;
 pub mod wormhole_emitter;
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+#[allow(unused_imports)]
+use crate::ID;
+
+/// Lifecycle of a sent message.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutboxStatus {
+    /// Posted to Wormhole.
+    #[default]
+    Sent,
+    /// Delivered on the target chain. Solana cannot observe delivery, so only
+    /// the owner sets this, through `set_outbox_status`.
+    Completed,
+}
+
+/// Record of a sent message, seeded by its Wormhole message account: looking
+/// it up takes that account's key, which the sender keeps or reads from the
+/// send transaction, and yields the message's sequence. The sender can close
+/// it, returning the rent to whoever paid it.
+#[account]
+#[derive(Default)]
+pub struct OutboxRecord {
+    pub sender: Pubkey,
+    pub sequence: u64,
+    pub batch_id: u32,
+    pub timestamp: i64,
+    pub status: OutboxStatus,
+    /// Refunded the rent on close: the payer, or the sponsor vault for a
+    /// sponsored send.
+    pub rent_payer: Pubkey,
+}
+
+impl OutboxRecord {
+    pub const MAXIMUM_SIZE: usize = 8 // discriminator
+        + 32 // sender
+        + 8 // sequence
+        + 4 // batch_id
+        + 8 // timestamp
+        + 1 // status
+        + 32 // rent_payer
+    ;
+    pub const SEED_PREFIX: &'static [u8; 6] = b"outbox";
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+#[allow(unused_imports)]
+use crate::ID;
+
+/// Remotely-controlled signer PDA of a (chain, sender) pair. The signer
+/// itself is a data-less PDA derived from the same pair so it can hold
+/// lamports and own token accounts. Used nonces are tracked in
+/// `RemoteNonceBitmap`s.
+#[account]
+#[derive(Default)]
+pub struct RemoteAccount {
+    pub chain: u16,
+    pub sender: [u8; 32],
+    pub signer_bump: u8,
+}
+
+impl RemoteAccount {
+    pub const MAXIMUM_SIZE: usize = 8 // discriminator
+        + 2 // chain
+        + 32 // sender
+        + 1 // signer_bump
+    ;
+    pub const SEED_PREFIX: &'static [u8; 14] = b"remote_account";
+    pub const SIGNER_SEED_PREFIX: &'static [u8; 13] = b"remote_signer";
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use wormhole_anchor_sdk::app::REPLAY_BITMAP_BYTES;
+
+#[allow(unused_imports)]
+use crate::ID;
+
+/// Used `Execute` nonces of a remote sender, one bit per nonce. Each bitmap
+/// covers `REPLAY_BITMAP_BITS` nonces. Nonces can be used in any order, so an
+/// `Execute` that keeps failing does not hold up the sender's later ones.
+#[account]
+pub struct RemoteNonceBitmap {
+    pub chain: u16,
+    pub sender: [u8; 32],
+    pub index: u64,
+    pub bits: [u8; REPLAY_BITMAP_BYTES],
+}
+
+impl RemoteNonceBitmap {
+    pub const MAXIMUM_SIZE: usize = 8 // discriminator
+        + 2 // chain
+        + 32 // sender
+        + 8 // index
+        + REPLAY_BITMAP_BYTES // bits
+    ;
+    pub const SEED_PREFIX: &'static [u8; 13] = b"remote_nonces";
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use wormhole_anchor_sdk::app::{self, REPLAY_BITMAP_BYTES};
+
+#[allow(unused_imports)]
+use crate::ID;
+
+/// Consumed sequences of a foreign emitter chain, one bit per sequence, for
+/// `consume_message`. Each bitmap covers `REPLAY_BITMAP_BITS` sequences.
+#[account]
+pub struct ReplayBitmap {
+    pub chain: u16,
+    pub index: u64,
+    pub bits: [u8; REPLAY_BITMAP_BYTES],
+}
+
+impl ReplayBitmap {
+    pub const MAXIMUM_SIZE: usize = 8 // discriminator
+        + 2 // chain
+        + 8 // index
+        + REPLAY_BITMAP_BYTES // bits
+    ;
+    pub const SEED_PREFIX: &'static [u8; 6] = b"replay";
+
+    /// Whether `sequence` is marked in the replay bitmap `account` (at the
+    /// PDA covering `sequence`). An uninitialized bitmap has nothing marked.
+    pub fn is_marked(account: &AccountInfo, sequence: u64) -> Result<bool> {
+        if account.data_is_empty() {
+            return Ok(false);
+        }
+        // Read the bits in place rather than copying the bitmap to the stack.
+        let data = account.try_borrow_data()?;
+        require!(
+            data.starts_with(Self::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        let bits = data
+            .get(8 + 2 + 8..Self::MAXIMUM_SIZE)
+            .and_then(|bits| <&[u8; REPLAY_BITMAP_BYTES]>::try_from(bits).ok())
+            .ok_or(ErrorCode::AccountDidNotDeserialize)?;
+        Ok(app::is_sequence_consumed(bits, sequence))
+    }
+}
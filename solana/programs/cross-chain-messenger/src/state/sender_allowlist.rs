@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+#[allow(unused_imports)]
+use crate::ID;
+
+/// Switch for the sender allowlist. Kept out of `Config` so deployed configs
+/// keep their size; the account does not exist until the owner first sets it.
+#[account]
+#[derive(Default)]
+pub struct SenderAllowlist {
+    /// Only senders with an `AllowedSender` entry may send messages.
+    pub enabled: bool,
+}
+
+impl SenderAllowlist {
+    pub const MAXIMUM_SIZE: usize = 8 // discriminator
+        + 1 // enabled
+    ;
+    pub const SEED_PREFIX: &'static [u8; 16] = b"sender_allowlist";
+
+    /// Whether the allowlist `account` (at the `SenderAllowlist` PDA) is
+    /// enabled. An uninitialized account means disabled.
+    pub fn is_enabled(account: &AccountInfo) -> Result<bool> {
+        if account.data_is_empty() {
+            return Ok(false);
+        }
+        let allowlist = Self::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+        Ok(allowlist.enabled)
+    }
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+#[allow(unused_imports)]
+use crate::ID;
+
+/// Lamports the sponsor vault may spend on behalf of a sponsored caller
+/// (keyed by pubkey) or a sponsored foreign emitter (keyed by chain), per
+/// period of `period` seconds. A zero period never resets `spent`.
+#[account]
+#[derive(Default)]
+pub struct Sponsorship {
+    pub spending_limit: u64,
+    pub spent: u64,
+    pub period: i64,
+    pub period_start: i64,
+}
+
+impl Sponsorship {
+    pub const MAXIMUM_SIZE: usize = 8 // discriminator
+        + 8 // spending_limit
+        + 8 // spent
+        + 8 // period
+        + 8 // period_start
+    ;
+    pub const CALLER_SEED_PREFIX: &'static [u8; 16] = b"sponsored_caller";
+    pub const EMITTER_SEED_PREFIX: &'static [u8; 17] = b"sponsored_emitter";
+
+    /// Record `amount` lamports as spent, starting a new period first if the
+    /// current one has elapsed. Returns false if the spending limit would be
+    /// exceeded.
+    pub fn charge(&mut self, amount: u64, now: i64) -> bool {
+        if self.period > 0 && now.saturating_sub(self.period_start) >= self.period {
+            self.spent = 0;
+            self.period_start = now;
+        }
+        match self.spent.checked_add(amount) {
+            Some(spent) if spent <= self.spending_limit => {
+                self.spent = spent;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Lamports the sponsor vault must keep to stay rent exempt.
+pub fn sponsor_vault_floor() -> Result<u64> {
+    Ok(Rent::get()?.minimum_balance(0))
+}
+
+#[test]
+fn sponsorship_charge_resets_each_period() {
+    let mut sponsorship = Sponsorship {
+        spending_limit: 100,
+        period: 60,
+        ..Default::default()
+    };
+
+    assert!(sponsorship.charge(100, 10));
+    assert!(!sponsorship.charge(1, 59));
+    assert!(sponsorship.charge(40, 60));
+    assert_eq!(sponsorship.spent, 40);
+    assert_eq!(sponsorship.period_start, 60);
+
+    // A zero period is a lifetime limit.
+    sponsorship.period = 0;
+    assert!(sponsorship.charge(60, 1_000));
+    assert!(!sponsorship.charge(1, 1_000_000));
+}
@@ -0,0 +1,7 @@
+import { deriveAddress } from "@certusone/wormhole-sdk/lib/cjs/solana";
+import { PublicKeyInitData } from "@solana/web3.js";
+
+// PDA of `recipientProgramId` that receives transfers sent to the program.
+export function deriveBridgeRecipientKey(recipientProgramId: PublicKeyInitData) {
+  return deriveAddress([Buffer.from("bridge_recipient")], recipientProgramId);
+}
@@ -30,11 +30,11 @@ export async function getForeignContractData(
   programId: PublicKeyInitData,
   chain: ChainId
 ): Promise<ForeignEmitter> {
-  const { address } = await createCrossChainBridgeProgramInterface(connection, programId)
+  const { peer } = await createCrossChainBridgeProgramInterface(connection, programId)
     .account.foreignContract.fetch(deriveForeignContractKey(programId, chain));
-  
+
   return {
-    chain,
-    address: Buffer.from(address),
+    chain: peer.chain as ChainId,
+    address: Buffer.from(peer.address),
   };
 }
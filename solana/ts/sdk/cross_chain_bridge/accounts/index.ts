@@ -1,5 +1,12 @@
+export * from "./bridgeRecipient";
 export * from "./foreignContract";
+export * from "./outboundChainLimit";
+export * from "./pendingRelease";
 export * from "./redeemerConfig";
+export * from "./registeredToken";
+export * from "./relayerFee";
 export * from "./senderConfig";
 export * from "./tmpTokenAccount";
+export * from "./transferCallSigner";
+export * from "./transferReceipt";
 export * from "./wormhole";
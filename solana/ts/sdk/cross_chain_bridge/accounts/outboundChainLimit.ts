@@ -0,0 +1,22 @@
+import { ChainId } from "@certusone/wormhole-sdk";
+import { deriveAddress } from "@certusone/wormhole-sdk/lib/cjs/solana";
+import { PublicKey, PublicKeyInitData } from "@solana/web3.js";
+
+export function deriveOutboundChainLimitKey(
+  programId: PublicKeyInitData,
+  mint: PublicKeyInitData,
+  chain: ChainId
+) {
+  return deriveAddress(
+    [
+      Buffer.from("outbound_limit"),
+      new PublicKey(mint).toBuffer(),
+      (() => {
+        const buf = Buffer.alloc(2);
+        buf.writeUInt16LE(chain);
+        return buf;
+      })(),
+    ],
+    programId
+  );
+}
@@ -0,0 +1,20 @@
+import { deriveAddress } from "@certusone/wormhole-sdk/lib/cjs/solana";
+import { PublicKeyInitData } from "@solana/web3.js";
+
+export function deriveGovernorConfigKey(programId: PublicKeyInitData) {
+  return deriveAddress([Buffer.from("governor")], programId);
+}
+
+export function derivePendingReleaseKey(
+  programId: PublicKeyInitData,
+  vaaHash: Buffer
+) {
+  return deriveAddress([Buffer.from("pending_release"), vaaHash], programId);
+}
+
+export function deriveEscrowTokenAccountKey(
+  programId: PublicKeyInitData,
+  vaaHash: Buffer
+) {
+  return deriveAddress([Buffer.from("escrow"), vaaHash], programId);
+}
@@ -0,0 +1,28 @@
+import { deriveAddress } from "@certusone/wormhole-sdk/lib/cjs/solana";
+import { Connection, PublicKey, PublicKeyInitData } from "@solana/web3.js";
+import { createCrossChainBridgeProgramInterface } from "../program";
+
+export function deriveRegisteredTokenKey(
+  programId: PublicKeyInitData,
+  mint: PublicKeyInitData
+) {
+  return deriveAddress(
+    [Buffer.from("registered_token"), new PublicKey(mint).toBuffer()],
+    programId
+  );
+}
+
+export interface RegisteredTokenData {
+  mint: PublicKey;
+  outboundEnabled: boolean;
+  inboundEnabled: boolean;
+}
+
+export async function getRegisteredTokenData(
+  connection: Connection,
+  programId: PublicKeyInitData,
+  mint: PublicKeyInitData
+): Promise<RegisteredTokenData> {
+  return createCrossChainBridgeProgramInterface(connection, programId)
+    .account.registeredToken.fetch(deriveRegisteredTokenKey(programId, mint));
+}
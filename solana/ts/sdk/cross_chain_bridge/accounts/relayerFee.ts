@@ -0,0 +1,30 @@
+import { ChainId } from "@certusone/wormhole-sdk";
+import { deriveAddress } from "@certusone/wormhole-sdk/lib/cjs/solana";
+import { PublicKey, PublicKeyInitData } from "@solana/web3.js";
+
+export function deriveChainRelayerFeeKey(
+  programId: PublicKeyInitData,
+  chain: ChainId
+) {
+  return deriveAddress(
+    [
+      Buffer.from("chain_relayer_fee"),
+      (() => {
+        const buf = Buffer.alloc(2);
+        buf.writeUInt16LE(chain);
+        return buf;
+      })(),
+    ],
+    programId
+  );
+}
+
+export function deriveTokenRelayerFeeKey(
+  programId: PublicKeyInitData,
+  mint: PublicKeyInitData
+) {
+  return deriveAddress(
+    [Buffer.from("token_relayer_fee"), new PublicKey(mint).toBuffer()],
+    programId
+  );
+}
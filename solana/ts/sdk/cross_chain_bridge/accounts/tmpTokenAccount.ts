@@ -1,12 +1,31 @@
 import { deriveAddress } from "@certusone/wormhole-sdk/lib/cjs/solana";
 import { PublicKey, PublicKeyInitData } from "@solana/web3.js";
 
-export function deriveTmpTokenAccountKey(
+// Tmp account a send moves the payer's tokens through.
+export function deriveSendTmpTokenAccountKey(
   programId: PublicKeyInitData,
-  mint: PublicKeyInitData
+  mint: PublicKeyInitData,
+  payer: PublicKeyInitData
 ) {
   return deriveAddress(
-    [Buffer.from("tmp"), new PublicKey(mint).toBuffer()],
+    [
+      Buffer.from("tmp"),
+      new PublicKey(mint).toBuffer(),
+      new PublicKey(payer).toBuffer(),
+    ],
+    programId
+  );
+}
+
+// Tmp account Token Bridge completes the transfer of the VAA with
+// `vaaHash` to.
+export function deriveRedeemTmpTokenAccountKey(
+  programId: PublicKeyInitData,
+  mint: PublicKeyInitData,
+  vaaHash: Buffer
+) {
+  return deriveAddress(
+    [Buffer.from("tmp"), new PublicKey(mint).toBuffer(), vaaHash],
     programId
   );
 }
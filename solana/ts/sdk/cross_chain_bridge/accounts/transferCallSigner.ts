@@ -0,0 +1,6 @@
+import { deriveAddress } from "@certusone/wormhole-sdk/lib/cjs/solana";
+import { PublicKeyInitData } from "@solana/web3.js";
+
+export function deriveTransferCallSignerKey(programId: PublicKeyInitData) {
+  return deriveAddress([Buffer.from("transfer_call")], programId);
+}
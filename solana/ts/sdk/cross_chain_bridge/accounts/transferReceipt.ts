@@ -0,0 +1,47 @@
+import { deriveAddress } from "@certusone/wormhole-sdk/lib/cjs/solana";
+import { BN } from "@coral-xyz/anchor";
+import { Connection, PublicKey, PublicKeyInitData } from "@solana/web3.js";
+import { createCrossChainBridgeProgramInterface } from "../program";
+
+// Receipts are seeded by the Wormhole message account of the transfer.
+export function deriveTransferReceiptKey(
+  programId: PublicKeyInitData,
+  wormholeMessage: PublicKeyInitData
+) {
+  return deriveAddress(
+    [
+      Buffer.from("transfer_receipt"),
+      new PublicKey(wormholeMessage).toBuffer(),
+    ],
+    programId
+  );
+}
+
+export function deriveRedeemReceiptKey(
+  programId: PublicKeyInitData,
+  vaaHash: Buffer
+) {
+  return deriveAddress([Buffer.from("redeem_receipt"), vaaHash], programId);
+}
+
+export interface TransferReceiptData {
+  sender: PublicKey;
+  mint: PublicKey;
+  amount: BN;
+  recipientChain: number;
+  recipient: number[];
+  sequence: BN;
+  timestamp: BN;
+  status: { sent: {} } | { completed: {} } | { refunded: {} };
+}
+
+export async function getTransferReceiptData(
+  connection: Connection,
+  programId: PublicKeyInitData,
+  wormholeMessage: PublicKeyInitData
+): Promise<TransferReceiptData> {
+  return createCrossChainBridgeProgramInterface(connection, programId)
+    .account.transferReceipt.fetch(
+      deriveTransferReceiptKey(programId, wormholeMessage)
+    );
+}
@@ -1,8 +1,13 @@
 export * from "./initialize";
+export * from "./recipient";
 export * from "./registerForeignContract";
 export * from "./redeemNativeTransferWithPayload";
 export * from "./redeemWrappedTransferWithPayload";
+export * from "./registerToken";
+export * from "./sendNativeTokens";
 export * from "./sendNativeTokensWithPayload";
+export * from "./sendSolWithPayload";
+export * from "./sendWrappedTokens";
 export * from "./sendWrappedTokensWithPayload";
 export * from "./types";
 export * from "./updateRelayerFee";
@@ -0,0 +1,69 @@
+import { Connection, PublicKey, PublicKeyInitData } from "@solana/web3.js";
+import { getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { deriveBridgeRecipientKey } from "../accounts";
+
+const PAYLOAD_ID_HELLO_V2 = 3;
+
+// Order of the program's `RecipientKind`.
+enum PayloadRecipientKind {
+  Wallet,
+  TokenAccount,
+  ProgramPda,
+  Call,
+}
+
+export interface RecipientAccounts {
+  recipient: PublicKey;
+  recipientTokenAccount: PublicKey;
+  // Program to call after the payout, for the call recipient kind.
+  recipientProgram: PublicKey | null;
+}
+
+// Resolves the accounts the redeem instructions pay out to from the token
+// transfer payload. Legacy payloads always name a wallet.
+export async function getRecipientAccounts(
+  connection: Connection,
+  mint: PublicKeyInitData,
+  payload: Buffer
+): Promise<RecipientAccounts> {
+  const address = new PublicKey(payload.subarray(1, 33));
+  const kind: PayloadRecipientKind =
+    payload[0] == PAYLOAD_ID_HELLO_V2 ? payload[33] : PayloadRecipientKind.Wallet;
+
+  switch (kind) {
+    case PayloadRecipientKind.Wallet: {
+      return {
+        recipient: address,
+        recipientTokenAccount: getAssociatedTokenAddressSync(
+          new PublicKey(mint),
+          address
+        ),
+        recipientProgram: null,
+      };
+    }
+    case PayloadRecipientKind.TokenAccount: {
+      const { owner } = await getAccount(connection, address);
+      return {
+        recipient: owner,
+        recipientTokenAccount: address,
+        recipientProgram: null,
+      };
+    }
+    case PayloadRecipientKind.ProgramPda:
+    case PayloadRecipientKind.Call: {
+      const recipient = deriveBridgeRecipientKey(address);
+      return {
+        recipient,
+        recipientTokenAccount: getAssociatedTokenAddressSync(
+          new PublicKey(mint),
+          recipient,
+          true
+        ),
+        recipientProgram: kind == PayloadRecipientKind.Call ? address : null,
+      };
+    }
+    default: {
+      throw new Error(`unknown recipient kind ${kind}`);
+    }
+  }
+}
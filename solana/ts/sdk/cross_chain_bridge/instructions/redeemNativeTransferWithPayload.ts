@@ -13,9 +13,18 @@ import {
   createCrossChainBridgeProgramInterface
 } from "../program";
 import {
+  deriveChainRelayerFeeKey,
+  deriveEscrowTokenAccountKey,
   deriveForeignContractKey,
-  deriveTmpTokenAccountKey,
+  deriveGovernorConfigKey,
+  derivePendingReleaseKey,
+  deriveRedeemReceiptKey,
   deriveRedeemerConfigKey,
+  deriveRedeemTmpTokenAccountKey,
+  deriveRegisteredTokenKey,
+  deriveTokenRelayerFeeKey,
+  deriveTransferCallSignerKey,
+  deriveTransferReceiptKey,
 } from "../accounts";
 import {
   deriveClaimKey,
@@ -38,6 +47,8 @@ import {
   deriveRedeemerAccountKey,
   deriveTokenBridgeConfigKey,
 } from "@certusone/wormhole-sdk/lib/cjs/solana/tokenBridge";
+import { getRecipientAccounts } from "./recipient";
+import { RedeemOptions } from "./types";
 
 export async function createRedeemNativeTransferWithPayloadInstruction(
   connection: Connection,
@@ -45,7 +56,8 @@ export async function createRedeemNativeTransferWithPayloadInstruction(
   payer: PublicKeyInitData,
   tokenBridgeProgramId: PublicKeyInitData,
   wormholeProgramId: PublicKeyInitData,
-  wormholeMessage: SignedVaa | ParsedTokenTransferVaa
+  wormholeMessage: SignedVaa | ParsedTokenTransferVaa,
+  opts?: RedeemOptions
 ): Promise<TransactionInstruction> {
   const program = createCrossChainBridgeProgramInterface(connection, programId);
 
@@ -55,7 +67,11 @@ export async function createRedeemNativeTransferWithPayloadInstruction(
 
   const mint = new PublicKey(parsed.tokenAddress);
 
-  const tmpTokenAccount = deriveTmpTokenAccountKey(programId, mint);
+  const tmpTokenAccount = deriveRedeemTmpTokenAccountKey(
+    programId,
+    mint,
+    parsed.hash
+  );
   const tokenBridgeAccounts = getCompleteTransferNativeWithPayloadCpiAccounts(
     tokenBridgeProgramId,
     wormholeProgramId,
@@ -64,17 +80,39 @@ export async function createRedeemNativeTransferWithPayloadInstruction(
     tmpTokenAccount
   );
 
-  const recipient = new PublicKey(parsed.tokenTransferPayload.subarray(1, 33));
-  const recipientTokenAccount = getAssociatedTokenAddressSync(mint, recipient);
+  const { recipient, recipientTokenAccount, recipientProgram } =
+    await getRecipientAccounts(
+      connection,
+      mint,
+      parsed.tokenTransferPayload
+    );
 
   return program.methods
-    .redeemNativeTransferWithPayload([...parsed.hash])
+    .redeemNativeTransferWithPayload([...parsed.hash], opts?.unwrap ?? false)
     .accounts({
       config: deriveRedeemerConfigKey(programId),
       foreignContract: deriveForeignContractKey(programId, parsed.emitterChain),
+      registeredToken: deriveRegisteredTokenKey(programId, mint),
+      chainRelayerFee: deriveChainRelayerFeeKey(programId, parsed.emitterChain),
+      tokenRelayerFee: deriveTokenRelayerFeeKey(programId, mint),
       tmpTokenAccount,
+      governorConfig: opts?.delayed ? deriveGovernorConfigKey(programId) : null,
+      pendingRelease: opts?.delayed
+        ? derivePendingReleaseKey(programId, parsed.hash)
+        : null,
+      escrowTokenAccount: opts?.delayed
+        ? deriveEscrowTokenAccountKey(programId, parsed.hash)
+        : null,
+      receipt: deriveRedeemReceiptKey(programId, parsed.hash),
+      refundedTransferReceipt: opts?.refundedTransferReceipt
+        ? deriveTransferReceiptKey(programId, opts.refundedTransferReceipt)
+        : null,
       recipientTokenAccount,
       recipient,
+      recipientProgram,
+      transferCallSigner: recipientProgram
+        ? deriveTransferCallSignerKey(programId)
+        : null,
       payerTokenAccount: getAssociatedTokenAddressSync(
         mint,
         new PublicKey(payer)
@@ -82,6 +120,7 @@ export async function createRedeemNativeTransferWithPayloadInstruction(
       tokenBridgeProgram: new PublicKey(tokenBridgeProgramId),
       ...tokenBridgeAccounts,
     })
+    .remainingAccounts(opts?.callAccounts ?? [])
     .instruction();
 }
 
@@ -13,9 +13,18 @@ import {
   createCrossChainBridgeProgramInterface
 } from "../program";
 import {
+  deriveChainRelayerFeeKey,
+  deriveEscrowTokenAccountKey,
   deriveForeignContractKey,
-  deriveTmpTokenAccountKey,
+  deriveGovernorConfigKey,
+  derivePendingReleaseKey,
+  deriveRedeemReceiptKey,
   deriveRedeemerConfigKey,
+  deriveRedeemTmpTokenAccountKey,
+  deriveRegisteredTokenKey,
+  deriveTokenRelayerFeeKey,
+  deriveTransferCallSignerKey,
+  deriveTransferReceiptKey,
 } from "../accounts";
 import {
   deriveClaimKey,
@@ -39,6 +48,8 @@ import {
   deriveWrappedMetaKey,
   deriveWrappedMintKey,
 } from "@certusone/wormhole-sdk/lib/cjs/solana/tokenBridge";
+import { getRecipientAccounts } from "./recipient";
+import { RedeemOptions } from "./types";
 
 export async function createRedeemWrappedTransferWithPayloadInstruction(
   connection: Connection,
@@ -46,7 +57,8 @@ export async function createRedeemWrappedTransferWithPayloadInstruction(
   payer: PublicKeyInitData,
   tokenBridgeProgramId: PublicKeyInitData,
   wormholeProgramId: PublicKeyInitData,
-  wormholeMessage: SignedVaa | ParsedTokenTransferVaa
+  wormholeMessage: SignedVaa | ParsedTokenTransferVaa,
+  opts?: RedeemOptions
 ): Promise<TransactionInstruction> {
   const program = createCrossChainBridgeProgramInterface(connection, programId);
 
@@ -60,7 +72,11 @@ export async function createRedeemWrappedTransferWithPayloadInstruction(
     parsed.tokenAddress
   );
 
-  const tmpTokenAccount = deriveTmpTokenAccountKey(programId, wrappedMint);
+  const tmpTokenAccount = deriveRedeemTmpTokenAccountKey(
+    programId,
+    wrappedMint,
+    parsed.hash
+  );
   const tokenBridgeAccounts = getCompleteTransferWrappedWithPayloadCpiAccounts(
     tokenBridgeProgramId,
     wormholeProgramId,
@@ -69,20 +85,39 @@ export async function createRedeemWrappedTransferWithPayloadInstruction(
     tmpTokenAccount
   );
 
-  const recipient = new PublicKey(parsed.tokenTransferPayload.subarray(1, 33));
-  const recipientTokenAccount = getAssociatedTokenAddressSync(
-    wrappedMint,
-    recipient
-  );
+  const { recipient, recipientTokenAccount, recipientProgram } =
+    await getRecipientAccounts(
+      connection,
+      wrappedMint,
+      parsed.tokenTransferPayload
+    );
 
   return program.methods
     .redeemWrappedTransferWithPayload([...parsed.hash])
     .accounts({
       config: deriveRedeemerConfigKey(programId),
       foreignContract: deriveForeignContractKey(programId, parsed.emitterChain),
+      registeredToken: deriveRegisteredTokenKey(programId, wrappedMint),
+      chainRelayerFee: deriveChainRelayerFeeKey(programId, parsed.emitterChain),
+      tokenRelayerFee: deriveTokenRelayerFeeKey(programId, wrappedMint),
       tmpTokenAccount,
+      governorConfig: opts?.delayed ? deriveGovernorConfigKey(programId) : null,
+      pendingRelease: opts?.delayed
+        ? derivePendingReleaseKey(programId, parsed.hash)
+        : null,
+      escrowTokenAccount: opts?.delayed
+        ? deriveEscrowTokenAccountKey(programId, parsed.hash)
+        : null,
+      receipt: deriveRedeemReceiptKey(programId, parsed.hash),
+      refundedTransferReceipt: opts?.refundedTransferReceipt
+        ? deriveTransferReceiptKey(programId, opts.refundedTransferReceipt)
+        : null,
       recipientTokenAccount,
       recipient,
+      recipientProgram,
+      transferCallSigner: recipientProgram
+        ? deriveTransferCallSignerKey(programId)
+        : null,
       payerTokenAccount: getAssociatedTokenAddressSync(
         wrappedMint,
         new PublicKey(payer)
@@ -90,6 +125,7 @@ export async function createRedeemWrappedTransferWithPayloadInstruction(
       tokenBridgeProgram: new PublicKey(tokenBridgeProgramId),
       ...tokenBridgeAccounts,
     })
+    .remainingAccounts(opts?.callAccounts ?? [])
     .instruction();
 }
 
@@ -0,0 +1,24 @@
+import { Connection, PublicKey, PublicKeyInitData, TransactionInstruction } from "@solana/web3.js";
+import { createCrossChainBridgeProgramInterface } from "../program";
+import { deriveSenderConfigKey, deriveRegisteredTokenKey } from "../accounts";
+
+export async function createRegisterTokenInstruction(
+  connection: Connection,
+  programId: PublicKeyInitData,
+  payer: PublicKeyInitData,
+  mint: PublicKeyInitData,
+  outboundEnabled: boolean,
+  inboundEnabled: boolean
+): Promise<TransactionInstruction> {
+  const program = createCrossChainBridgeProgramInterface(connection, programId);
+
+  return program.methods
+    .registerToken(outboundEnabled, inboundEnabled)
+    .accounts({
+      owner: new PublicKey(payer),
+      config: deriveSenderConfigKey(programId),
+      mint: new PublicKey(mint),
+      registeredToken: deriveRegisteredTokenKey(programId, mint),
+    })
+    .instruction();
+}
@@ -0,0 +1,74 @@
+import { Connection, PublicKey, PublicKeyInitData, TransactionInstruction } from "@solana/web3.js";
+import { getTransferNativeWithPayloadCpiAccounts } from "@certusone/wormhole-sdk/lib/cjs/solana";
+import { createCrossChainBridgeProgramInterface } from "../program";
+import {
+  deriveForeignContractKey,
+  deriveOutboundChainLimitKey,
+  deriveRegisteredTokenKey,
+  deriveSendTmpTokenAccountKey,
+  deriveSenderConfigKey,
+  deriveTransferReceiptKey,
+} from "../accounts";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { SendPlainTokensParams } from "./types";
+import { BN } from "@coral-xyz/anchor";
+
+// Sends a standard Token Bridge transfer straight to the recipient, which
+// anyone can redeem on the target chain for `params.relayerFee`.
+// `wormholeMessage` is a new keypair the Wormhole message is posted to. It
+// must sign the transaction along with the payer.
+export async function createSendNativeTokensInstruction(
+  connection: Connection,
+  programId: PublicKeyInitData,
+  payer: PublicKeyInitData,
+  tokenBridgeProgramId: PublicKeyInitData,
+  wormholeProgramId: PublicKeyInitData,
+  mint: PublicKeyInitData,
+  wormholeMessage: PublicKeyInitData,
+  params: SendPlainTokensParams
+): Promise<TransactionInstruction> {
+  const program = createCrossChainBridgeProgramInterface(connection, programId);
+
+  const fromTokenAccount = getAssociatedTokenAddressSync(
+    new PublicKey(mint),
+    new PublicKey(payer)
+  );
+  const tmpTokenAccount = deriveSendTmpTokenAccountKey(programId, mint, payer);
+  const tokenBridgeAccounts = getTransferNativeWithPayloadCpiAccounts(
+    programId,
+    tokenBridgeProgramId,
+    wormholeProgramId,
+    payer,
+    wormholeMessage,
+    fromTokenAccount,
+    mint
+  );
+
+  return program.methods
+    .sendNativeTokens(
+      params.batchId,
+      new BN(params.amount.toString()),
+      [...params.recipientAddress],
+      params.recipientChain,
+      new BN(params.relayerFee.toString()),
+      params.requireExact ?? false
+    )
+    .accounts({
+      config: deriveSenderConfigKey(programId),
+      foreignContract: deriveForeignContractKey(
+        programId,
+        params.recipientChain
+      ),
+      registeredToken: deriveRegisteredTokenKey(programId, mint),
+      outboundChainLimit: deriveOutboundChainLimitKey(
+        programId,
+        mint,
+        params.recipientChain
+      ),
+      tmpTokenAccount,
+      transferReceipt: deriveTransferReceiptKey(programId, wormholeMessage),
+      tokenBridgeProgram: new PublicKey(tokenBridgeProgramId),
+      ...tokenBridgeAccounts,
+    })
+    .instruction();
+}
@@ -3,15 +3,18 @@ import { getTransferNativeWithPayloadCpiAccounts } from "@certusone/wormhole-sdk
 import { createCrossChainBridgeProgramInterface } from "../program";
 import {
   deriveForeignContractKey,
+  deriveOutboundChainLimitKey,
+  deriveRegisteredTokenKey,
+  deriveSendTmpTokenAccountKey,
   deriveSenderConfigKey,
-  deriveTokenTransferMessageKey,
-  deriveTmpTokenAccountKey,
+  deriveTransferReceiptKey,
 } from "../accounts";
-import { getProgramSequenceTracker } from "@certusone/wormhole-sdk/lib/cjs/solana/wormhole";
 import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { SendTokensParams } from "./types";
 import { BN } from "@coral-xyz/anchor";
 
+// `wormholeMessage` is a new keypair the Wormhole message is posted to. It
+// must sign the transaction along with the payer.
 export async function createSendNativeTokensWithPayloadInstruction(
   connection: Connection,
   programId: PublicKeyInitData,
@@ -19,51 +22,51 @@ export async function createSendNativeTokensWithPayloadInstruction(
   tokenBridgeProgramId: PublicKeyInitData,
   wormholeProgramId: PublicKeyInitData,
   mint: PublicKeyInitData,
+  wormholeMessage: PublicKeyInitData,
   params: SendTokensParams
 ): Promise<TransactionInstruction> {
   const program = createCrossChainBridgeProgramInterface(connection, programId);
 
-  return getProgramSequenceTracker(
-    connection,
+  const fromTokenAccount = getAssociatedTokenAddressSync(
+    new PublicKey(mint),
+    new PublicKey(payer)
+  );
+  const tmpTokenAccount = deriveSendTmpTokenAccountKey(programId, mint, payer);
+  const tokenBridgeAccounts = getTransferNativeWithPayloadCpiAccounts(
+    programId,
     tokenBridgeProgramId,
-    wormholeProgramId
-  )
-    .then((tracker) =>
-      deriveTokenTransferMessageKey(programId, tracker.sequence + 1n)
+    wormholeProgramId,
+    payer,
+    wormholeMessage,
+    fromTokenAccount,
+    mint
+  );
+
+  return program.methods
+    .sendNativeTokensWithPayload(
+      params.batchId,
+      new BN(params.amount.toString()),
+      [...params.recipientAddress],
+      params.recipientChain,
+      params.options ?? null,
+      params.requireExact ?? false
     )
-    .then((message) => {
-      const fromTokenAccount = getAssociatedTokenAddressSync(
-        new PublicKey(mint),
-        new PublicKey(payer)
-      );
-      const tmpTokenAccount = deriveTmpTokenAccountKey(programId, mint);
-      const tokenBridgeAccounts = getTransferNativeWithPayloadCpiAccounts(
+    .accounts({
+      config: deriveSenderConfigKey(programId),
+      foreignContract: deriveForeignContractKey(
         programId,
-        tokenBridgeProgramId,
-        wormholeProgramId,
-        payer,
-        message,
-        fromTokenAccount,
-        mint
-      );
-
-      return program.methods
-        .sendNativeTokensWithPayload(
-          params.batchId,
-          new BN(params.amount.toString()),
-          [...params.recipientAddress],
-          params.recipientChain
-        )
-        .accounts({
-          config: deriveSenderConfigKey(programId),
-          foreignContract: deriveForeignContractKey(
-            programId,
-            params.recipientChain
-          ),
-          tmpTokenAccount,
-          tokenBridgeProgram: new PublicKey(tokenBridgeProgramId),
-          ...tokenBridgeAccounts,
-        })
-        .instruction();
-    });
+        params.recipientChain
+      ),
+      registeredToken: deriveRegisteredTokenKey(programId, mint),
+      outboundChainLimit: deriveOutboundChainLimitKey(
+        programId,
+        mint,
+        params.recipientChain
+      ),
+      tmpTokenAccount,
+      transferReceipt: deriveTransferReceiptKey(programId, wormholeMessage),
+      tokenBridgeProgram: new PublicKey(tokenBridgeProgramId),
+      ...tokenBridgeAccounts,
+    })
+    .instruction();
 }
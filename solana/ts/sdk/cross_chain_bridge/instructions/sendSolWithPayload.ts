@@ -0,0 +1,74 @@
+import { Connection, PublicKey, PublicKeyInitData, TransactionInstruction } from "@solana/web3.js";
+import { getTransferNativeWithPayloadCpiAccounts } from "@certusone/wormhole-sdk/lib/cjs/solana";
+import { createCrossChainBridgeProgramInterface } from "../program";
+import {
+  deriveForeignContractKey,
+  deriveOutboundChainLimitKey,
+  deriveRegisteredTokenKey,
+  deriveSendTmpTokenAccountKey,
+  deriveSenderConfigKey,
+  deriveTransferReceiptKey,
+} from "../accounts";
+import { NATIVE_MINT } from "@solana/spl-token";
+import { SendTokensParams } from "./types";
+import { BN } from "@coral-xyz/anchor";
+
+// Sends `params.amount` lamports from the payer as wrapped SOL. The program
+// wraps the lamports itself, so the payer needs no wSOL token account.
+// `wormholeMessage` is a new keypair the Wormhole message is posted to. It
+// must sign the transaction along with the payer.
+export async function createSendSolWithPayloadInstruction(
+  connection: Connection,
+  programId: PublicKeyInitData,
+  payer: PublicKeyInitData,
+  tokenBridgeProgramId: PublicKeyInitData,
+  wormholeProgramId: PublicKeyInitData,
+  wormholeMessage: PublicKeyInitData,
+  params: SendTokensParams
+): Promise<TransactionInstruction> {
+  const program = createCrossChainBridgeProgramInterface(connection, programId);
+
+  const tmpTokenAccount = deriveSendTmpTokenAccountKey(
+    programId,
+    NATIVE_MINT,
+    payer
+  );
+  const { fromTokenAccount: _, ...tokenBridgeAccounts } =
+    getTransferNativeWithPayloadCpiAccounts(
+      programId,
+      tokenBridgeProgramId,
+      wormholeProgramId,
+      payer,
+      wormholeMessage,
+      tmpTokenAccount,
+      NATIVE_MINT
+    );
+
+  return program.methods
+    .sendSolWithPayload(
+      params.batchId,
+      new BN(params.amount.toString()),
+      [...params.recipientAddress],
+      params.recipientChain,
+      params.options ?? null,
+      params.requireExact ?? false
+    )
+    .accounts({
+      config: deriveSenderConfigKey(programId),
+      foreignContract: deriveForeignContractKey(
+        programId,
+        params.recipientChain
+      ),
+      registeredToken: deriveRegisteredTokenKey(programId, NATIVE_MINT),
+      outboundChainLimit: deriveOutboundChainLimitKey(
+        programId,
+        NATIVE_MINT,
+        params.recipientChain
+      ),
+      tmpTokenAccount,
+      transferReceipt: deriveTransferReceiptKey(programId, wormholeMessage),
+      tokenBridgeProgram: new PublicKey(tokenBridgeProgramId),
+      ...tokenBridgeAccounts,
+    })
+    .instruction();
+}
@@ -0,0 +1,81 @@
+import { Connection, PublicKey, PublicKeyInitData, TransactionInstruction } from "@solana/web3.js";
+import { getTransferWrappedWithPayloadCpiAccounts } from "@certusone/wormhole-sdk/lib/cjs/solana";
+import { createCrossChainBridgeProgramInterface } from "../program";
+import {
+  deriveForeignContractKey,
+  deriveOutboundChainLimitKey,
+  deriveRegisteredTokenKey,
+  deriveSendTmpTokenAccountKey,
+  deriveSenderConfigKey,
+  deriveTransferReceiptKey,
+} from "../accounts";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { SendPlainTokensParams } from "./types";
+import { getWrappedMeta } from "@certusone/wormhole-sdk/lib/cjs/solana/tokenBridge";
+import { BN } from "@coral-xyz/anchor";
+
+// Sends a standard Token Bridge transfer straight to the recipient, which
+// anyone can redeem on the target chain for `params.relayerFee`.
+// `wormholeMessage` is a new keypair the Wormhole message is posted to. It
+// must sign the transaction along with the payer.
+export async function createSendWrappedTokensInstruction(
+  connection: Connection,
+  programId: PublicKeyInitData,
+  payer: PublicKeyInitData,
+  tokenBridgeProgramId: PublicKeyInitData,
+  wormholeProgramId: PublicKeyInitData,
+  mint: PublicKeyInitData,
+  wormholeMessage: PublicKeyInitData,
+  params: SendPlainTokensParams
+): Promise<TransactionInstruction> {
+  const program = createCrossChainBridgeProgramInterface(connection, programId);
+
+  const fromTokenAccount = getAssociatedTokenAddressSync(
+    new PublicKey(mint),
+    new PublicKey(payer)
+  );
+  const tmpTokenAccount = deriveSendTmpTokenAccountKey(programId, mint, payer);
+
+  const wrappedMeta = await getWrappedMeta(
+    connection,
+    tokenBridgeProgramId,
+    mint
+  );
+  const tokenBridgeAccounts = getTransferWrappedWithPayloadCpiAccounts(
+    programId,
+    tokenBridgeProgramId,
+    wormholeProgramId,
+    payer,
+    wormholeMessage,
+    fromTokenAccount,
+    wrappedMeta.chain,
+    wrappedMeta.tokenAddress
+  );
+
+  return program.methods
+    .sendWrappedTokens(
+      params.batchId,
+      new BN(params.amount.toString()),
+      [...params.recipientAddress],
+      params.recipientChain,
+      new BN(params.relayerFee.toString())
+    )
+    .accounts({
+      config: deriveSenderConfigKey(programId),
+      foreignContract: deriveForeignContractKey(
+        programId,
+        params.recipientChain
+      ),
+      registeredToken: deriveRegisteredTokenKey(programId, mint),
+      outboundChainLimit: deriveOutboundChainLimitKey(
+        programId,
+        mint,
+        params.recipientChain
+      ),
+      tmpTokenAccount,
+      transferReceipt: deriveTransferReceiptKey(programId, wormholeMessage),
+      tokenBridgeProgram: new PublicKey(tokenBridgeProgramId),
+      ...tokenBridgeAccounts,
+    })
+    .instruction();
+}
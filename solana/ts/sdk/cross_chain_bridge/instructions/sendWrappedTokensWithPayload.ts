@@ -3,16 +3,19 @@ import { getTransferWrappedWithPayloadCpiAccounts } from "@certusone/wormhole-sd
 import { createCrossChainBridgeProgramInterface } from "../program";
 import {
   deriveForeignContractKey,
+  deriveOutboundChainLimitKey,
+  deriveRegisteredTokenKey,
+  deriveSendTmpTokenAccountKey,
   deriveSenderConfigKey,
-  deriveTokenTransferMessageKey,
-  deriveTmpTokenAccountKey,
+  deriveTransferReceiptKey,
 } from "../accounts";
-import { getProgramSequenceTracker } from "@certusone/wormhole-sdk/lib/cjs/solana/wormhole";
 import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { SendTokensParams } from "./types";
 import { getWrappedMeta } from "@certusone/wormhole-sdk/lib/cjs/solana/tokenBridge";
 import { BN } from "@coral-xyz/anchor";
 
+// `wormholeMessage` is a new keypair the Wormhole message is posted to. It
+// must sign the transaction along with the payer.
 export async function createSendWrappedTokensWithPayloadInstruction(
   connection: Connection,
   programId: PublicKeyInitData,
@@ -20,58 +23,57 @@ export async function createSendWrappedTokensWithPayloadInstruction(
   tokenBridgeProgramId: PublicKeyInitData,
   wormholeProgramId: PublicKeyInitData,
   mint: PublicKeyInitData,
+  wormholeMessage: PublicKeyInitData,
   params: SendTokensParams
 ): Promise<TransactionInstruction> {
   const program = createCrossChainBridgeProgramInterface(connection, programId);
 
-  return getProgramSequenceTracker(
+  const fromTokenAccount = getAssociatedTokenAddressSync(
+    new PublicKey(mint),
+    new PublicKey(payer)
+  );
+  const tmpTokenAccount = deriveSendTmpTokenAccountKey(programId, mint, payer);
+
+  const wrappedMeta = await getWrappedMeta(
     connection,
     tokenBridgeProgramId,
-    wormholeProgramId
-  )
-    .then((tracker) =>
-      deriveTokenTransferMessageKey(programId, tracker.sequence + 1n)
-    )
-    .then(async (message) => {
-      const fromTokenAccount = getAssociatedTokenAddressSync(
-        new PublicKey(mint),
-        new PublicKey(payer)
-      );
-      const tmpTokenAccount = deriveTmpTokenAccountKey(programId, mint);
+    mint
+  );
+  const tokenBridgeAccounts = getTransferWrappedWithPayloadCpiAccounts(
+    programId,
+    tokenBridgeProgramId,
+    wormholeProgramId,
+    payer,
+    wormholeMessage,
+    fromTokenAccount,
+    wrappedMeta.chain,
+    wrappedMeta.tokenAddress
+  );
 
-      const wrappedMeta = await getWrappedMeta(
-        connection,
-        tokenBridgeProgramId,
-        mint
-      );
-      const tokenBridgeAccounts = getTransferWrappedWithPayloadCpiAccounts(
+  return program.methods
+    .sendWrappedTokensWithPayload(
+      params.batchId,
+      new BN(params.amount.toString()),
+      [...params.recipientAddress],
+      params.recipientChain,
+      params.options ?? null
+    )
+    .accounts({
+      config: deriveSenderConfigKey(programId),
+      foreignContract: deriveForeignContractKey(
         programId,
-        tokenBridgeProgramId,
-        wormholeProgramId,
-        payer,
-        message,
-        fromTokenAccount,
-        wrappedMeta.chain,
-        wrappedMeta.tokenAddress
-      );
-
-      return program.methods
-        .sendWrappedTokensWithPayload(
-          params.batchId,
-          new BN(params.amount.toString()),
-          [...params.recipientAddress],
-          params.recipientChain
-        )
-        .accounts({
-          config: deriveSenderConfigKey(programId),
-          foreignContract: deriveForeignContractKey(
-            programId,
-            params.recipientChain
-          ),
-          tmpTokenAccount,
-          tokenBridgeProgram: new PublicKey(tokenBridgeProgramId),
-          ...tokenBridgeAccounts,
-        })
-        .instruction();
-    });
+        params.recipientChain
+      ),
+      registeredToken: deriveRegisteredTokenKey(programId, mint),
+      outboundChainLimit: deriveOutboundChainLimitKey(
+        programId,
+        mint,
+        params.recipientChain
+      ),
+      tmpTokenAccount,
+      transferReceipt: deriveTransferReceiptKey(programId, wormholeMessage),
+      tokenBridgeProgram: new PublicKey(tokenBridgeProgramId),
+      ...tokenBridgeAccounts,
+    })
+    .instruction();
 }
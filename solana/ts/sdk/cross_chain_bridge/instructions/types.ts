@@ -1,8 +1,64 @@
 import {ChainId } from "@certusone/wormhole-sdk";
+import { BN } from "@coral-xyz/anchor";
+import { AccountMeta, PublicKeyInitData } from "@solana/web3.js";
 
 export interface SendTokensParams {
   batchId: number;
   amount: bigint;
   recipientAddress: Buffer;
   recipientChain: ChainId;
+  options?: TransferOptions;
+  // Fail instead of leaving truncation dust with the sender. Native and SOL
+  // sends only.
+  requireExact?: boolean;
+}
+
+export interface SendPlainTokensParams {
+  batchId: number;
+  amount: bigint;
+  recipientAddress: Buffer;
+  recipientChain: ChainId;
+  // Paid by Token Bridge to whoever redeems the transfer, in the mint's units.
+  relayerFee: bigint;
+  // Fail instead of leaving truncation dust with the sender. Native sends
+  // only.
+  requireExact?: boolean;
+}
+
+// How the redeeming side resolves the payload's recipient.
+export type RecipientKind =
+  | { wallet: {} }
+  | { tokenAccount: {} }
+  | { programPda: {} }
+  | { call: {} };
+
+export interface TransferOptions {
+  // Maximum relayer fee the recipient pays on the target chain (normalized),
+  // zero for no cap.
+  maxRelayerFee: BN;
+  // Relayer fee tip for the target chain's relayer (normalized).
+  targetRelayerFee: BN;
+  // Native gas the relayer drops off to the recipient on the target chain.
+  gasDropoff: BN;
+  memo: number[];
+  // Origin chain address refunds are sent to instead of the sender, if
+  // non-zero.
+  refundAddress: number[];
+  appData: Buffer;
+  recipientKind: RecipientKind;
+}
+
+export interface RedeemOptions {
+  // The transfer is above the token's inbound threshold or beyond its cap,
+  // so it is held in escrow until released.
+  delayed?: boolean;
+  // Redeem wrapped SOL to the recipient's wallet as lamports instead of to
+  // their wSOL token account.
+  unwrap?: boolean;
+  // Accounts the call recipient program needs, passed after the redeem
+  // instruction's accounts.
+  callAccounts?: AccountMeta[];
+  // Wormhole message account of the outbound transfer a refund VAA sends
+  // back. Its receipt is marked refunded.
+  refundedTransferReceipt?: PublicKeyInitData;
 }
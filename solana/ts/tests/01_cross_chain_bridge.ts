@@ -3,6 +3,7 @@ import chaiAsPromised from 'chai-as-promised';
 chaiUse(chaiAsPromised)
 import {
   Connection,
+  Keypair,
   PublicKey
 } from "@solana/web3.js";
 import { getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
@@ -258,25 +259,17 @@ describe("Cross Chain Bridge", function() {
   const sendAmount = 31337n;
   const recipientAddress = Buffer.alloc(32, "1337beef", "hex");
 
-  const getWormholeSequence = async () => (
-      await wormhole.getProgramSequenceTracker(connection, TOKEN_BRIDGE_PID, CORE_BRIDGE_PID)
-    ).value() + 1n;
-
-  const verifyWormholeMessage = async (sequence: bigint) => {
+  const verifyWormholeMessage = async (message: PublicKey) => {
     const payload =
       parseTokenTransferPayload(
-        (await wormhole.getPostedMessage(
-          connection,
-          crossChainBridge.deriveTokenTransferMessageKey(CROSS_CHAIN_BRIDGE_PID, sequence)
-        )).message.payload
+        (await wormhole.getPostedMessage(connection, message)).message.payload
       ).tokenTransferPayload;
     
     expect(payload.readUint8(0)).equals(1);
     expect(recipientAddress).deep.equals(payload.subarray(1, 33));
   }
 
-  const verifyTmpTokenAccountDoesNotExist = async (mint: PublicKey) => {
-    const tmpTokenAccountKey = crossChainBridge.deriveTmpTokenAccountKey(CROSS_CHAIN_BRIDGE_PID, mint);
+  const verifyTmpTokenAccountDoesNotExist = async (tmpTokenAccountKey: PublicKey) => {
     await expect(getAccount(connection, tmpTokenAccountKey)).to.be.rejected;
   }
 
@@ -306,12 +299,47 @@ describe("Cross Chain Bridge", function() {
       const truncation = (isNative ? 10n ** BigInt(decimals - 8) : 1n);
       const receiveAmount = ((sendAmount / 2n) / truncation) * truncation;
 
+      describe(`Register Token`, function() {
+        const createRegisterTokenIx = (sender: PublicKey) =>
+          crossChainBridge.createRegisterTokenInstruction(
+            connection,
+            CROSS_CHAIN_BRIDGE_PID,
+            sender,
+            mint,
+            true,
+            true
+          );
+
+        it("Cannot Register as Non-Owner", async function() {
+          await expectIxToFailWithError(
+            await createRegisterTokenIx(relayer.publicKey),
+            "OwnerOnly",
+            relayer
+          );
+        });
+
+        it("Finally Register Token", async function() {
+          await expectIxToSucceed(createRegisterTokenIx(payer.publicKey));
+
+          const registeredTokenData =
+            await crossChainBridge.getRegisteredTokenData(connection, CROSS_CHAIN_BRIDGE_PID, mint);
+          expect(registeredTokenData.mint).deep.equals(mint);
+          expect(registeredTokenData.outboundEnabled).equals(true);
+          expect(registeredTokenData.inboundEnabled).equals(true);
+        });
+      });
+
       describe(`Send Tokens With Payload`, function() {
+        // Failed sends leave the message account unused, so all of them and
+        // the final send share it.
+        const message = Keypair.generate();
+
         const createSendTokensWithPayloadIx = (opts?: {
           sender?: PublicKey,
           amount?: bigint,
           recipientAddress?: Buffer,
           recipientChain?: ChainId,
+          requireExact?: boolean,
         }) => 
           ( isNative
           ? crossChainBridge.createSendNativeTokensWithPayloadInstruction
@@ -323,26 +351,39 @@ describe("Cross Chain Bridge", function() {
           TOKEN_BRIDGE_PID,
           CORE_BRIDGE_PID,
           mint,
+          message.publicKey,
           {
             batchId,
             amount: opts?.amount ?? sendAmount,
             recipientAddress: opts?.recipientAddress ?? recipientAddress,
             recipientChain: opts?.recipientChain ?? foreignChain,
+            requireExact: opts?.requireExact,
           }
         );
 
-        if (isNative && decimals > 8)
+        if (isNative && decimals > 8) {
           it("Cannot Send Amount Less Than Bridgeable", async function() {
             await expectIxToFailWithError(
               await createSendTokensWithPayloadIx({amount: 9n}),
-              "ZeroBridgeAmount"
+              "ZeroBridgeAmount",
+              [payer, message]
+            );
+          });
+
+          it("Cannot Send Inexact Amount When Exact Is Required", async function() {
+            await expectIxToFailWithError(
+              await createSendTokensWithPayloadIx({requireExact: true}),
+              "InexactAmount",
+              [payer, message]
             );
           });
+        }
 
         it("Cannot Send To Unregistered Foreign Contract", async function() {
           await expectIxToFailWithError(
             await createSendTokensWithPayloadIx({recipientChain: invalidChain}),
-            "AccountNotInitialized"
+            "AccountNotInitialized",
+            [payer, message]
           );
         });
 
@@ -350,7 +391,8 @@ describe("Cross Chain Bridge", function() {
           it(`Cannot Send To Chain ID == ${recipientChain}`, async function() {
             await expectIxToFailWithError(
               await createSendTokensWithPayloadIx({recipientChain}),
-              "AnchorError caused by account: foreign_contract. Error Code: AccountNotInitialized"
+              "AnchorError caused by account: foreign_contract. Error Code: AccountNotInitialized",
+              [payer, message]
             );
           })
         );
@@ -358,21 +400,80 @@ describe("Cross Chain Bridge", function() {
         it("Cannot Send To Zero Address", async function() {
           await expectIxToFailWithError(
             await createSendTokensWithPayloadIx({recipientAddress: Buffer.alloc(32)}),
-            "InvalidRecipient"
+            "InvalidRecipient",
+            [payer, message]
           );
         });
 
         it("Finally Send Tokens With Payload", async function() {
-          const sequence = await getWormholeSequence();
-
           const balanceBefore = await getTokenBalance(recipientTokenAccount);
           const computeUnits = 250_000;
-          await expectIxToSucceed(createSendTokensWithPayloadIx(), computeUnits);
+          await expectIxToSucceed(createSendTokensWithPayloadIx(), [payer, message], computeUnits);
           const balanceChange = balanceBefore - await getTokenBalance(recipientTokenAccount);
           expect(balanceChange).equals((sendAmount / truncation) * truncation);
 
-          await verifyWormholeMessage(sequence);
-          await verifyTmpTokenAccountDoesNotExist(mint);
+          await verifyWormholeMessage(message.publicKey);
+
+          const receipt = await crossChainBridge.getTransferReceiptData(
+            connection,
+            CROSS_CHAIN_BRIDGE_PID,
+            message.publicKey
+          );
+          expect(receipt.sender).deep.equals(payer.publicKey);
+          expect(receipt.mint).deep.equals(mint);
+          expect(receipt.recipientChain).equals(foreignChain);
+          expect(Buffer.from(receipt.recipient)).deep.equals(recipientAddress);
+          expect(receipt.status).deep.equals({ sent: {} });
+          await verifyTmpTokenAccountDoesNotExist(
+            crossChainBridge.deriveSendTmpTokenAccountKey(CROSS_CHAIN_BRIDGE_PID, mint, payer.publicKey)
+          );
+        });
+      });
+
+      describe(`Send Tokens`, function() {
+        const message = Keypair.generate();
+        const bridgedAmount = (sendAmount / truncation) * truncation;
+
+        const createSendTokensIx = (relayerFee: bigint) =>
+          ( isNative
+          ? crossChainBridge.createSendNativeTokensInstruction
+          : crossChainBridge.createSendWrappedTokensInstruction
+          )(
+          connection,
+          CROSS_CHAIN_BRIDGE_PID,
+          payer.publicKey,
+          TOKEN_BRIDGE_PID,
+          CORE_BRIDGE_PID,
+          mint,
+          message.publicKey,
+          {
+            batchId,
+            amount: sendAmount,
+            recipientAddress,
+            recipientChain: foreignChain,
+            relayerFee,
+          }
+        );
+
+        it("Cannot Send With Relayer Fee Above Amount", async function() {
+          await expectIxToFailWithError(
+            await createSendTokensIx(bridgedAmount + 1n),
+            "InvalidRelayerFee",
+            [payer, message]
+          );
+        });
+
+        it("Finally Send Tokens", async function() {
+          const balanceBefore = await getTokenBalance(recipientTokenAccount);
+          await expectIxToSucceed(createSendTokensIx(0n), [payer, message], 250_000);
+          const balanceChange = balanceBefore - await getTokenBalance(recipientTokenAccount);
+          expect(balanceChange).equals(bridgedAmount);
+
+          // Plain transfers carry no payload.
+          const { tokenTransferPayload } = parseTokenTransferPayload(
+            (await wormhole.getPostedMessage(connection, message.publicKey)).message.payload
+          );
+          expect(tokenTransferPayload.length).equals(0);
         });
       });
 
@@ -449,8 +550,11 @@ describe("Cross Chain Bridge", function() {
                 ? new PublicKey(parsed.tokenAddress)
                 : deriveWrappedMintKey(TOKEN_BRIDGE_PID,  parsed.tokenChain, parsed.tokenAddress);
 
-              const tmpTokenAccount =
-                crossChainBridge.deriveTmpTokenAccountKey(CROSS_CHAIN_BRIDGE_PID, parsedMint);
+              const tmpTokenAccount = crossChainBridge.deriveRedeemTmpTokenAccountKey(
+                CROSS_CHAIN_BRIDGE_PID,
+                parsedMint,
+                parsed.hash
+              );
               const tokenBridgeAccounts = (isNative
                 ? crossChainBridge.getCompleteTransferNativeWithPayloadCpiAccounts
                 : crossChainBridge.getCompleteTransferWrappedWithPayloadCpiAccounts)(
@@ -462,17 +566,30 @@ describe("Cross Chain Bridge", function() {
                 );
 
               const method = isNative
-                ? program.methods.redeemNativeTransferWithPayload
-                : program.methods.redeemWrappedTransferWithPayload;
-              
-              return method([...parsed.hash])
+                ? program.methods.redeemNativeTransferWithPayload([...parsed.hash], false)
+                : program.methods.redeemWrappedTransferWithPayload([...parsed.hash]);
+
+              return method
                 .accounts({
                   config: crossChainBridge.deriveRedeemerConfigKey(CROSS_CHAIN_BRIDGE_PID),
                   foreignContract:
                     crossChainBridge.deriveForeignContractKey(CROSS_CHAIN_BRIDGE_PID, parsed.emitterChain as ChainId),
+                  registeredToken:
+                    crossChainBridge.deriveRegisteredTokenKey(CROSS_CHAIN_BRIDGE_PID, parsedMint),
+                  chainRelayerFee:
+                    crossChainBridge.deriveChainRelayerFeeKey(CROSS_CHAIN_BRIDGE_PID, parsed.emitterChain as ChainId),
+                  tokenRelayerFee:
+                    crossChainBridge.deriveTokenRelayerFeeKey(CROSS_CHAIN_BRIDGE_PID, parsedMint),
                   tmpTokenAccount,
+                  governorConfig: null,
+                  pendingRelease: null,
+                  escrowTokenAccount: null,
+                  receipt: crossChainBridge.deriveRedeemReceiptKey(CROSS_CHAIN_BRIDGE_PID, parsed.hash),
+                  refundedTransferReceipt: null,
                   recipientTokenAccount: bogusTokenAccount,
                   recipient: relayer.publicKey,
+                  recipientProgram: null,
+                  transferCallSigner: null,
                   payerTokenAccount: getAssociatedTokenAddressSync(parsedMint, relayer.publicKey),
                   tokenBridgeProgram: TOKEN_BRIDGE_PID,
                   ...tokenBridgeAccounts,
@@ -513,7 +630,13 @@ describe("Cross Chain Bridge", function() {
               expect(balancesChange[1]).equals(relayerAmount);
             }
 
-            await verifyTmpTokenAccountDoesNotExist(mint);
+            await verifyTmpTokenAccountDoesNotExist(
+              crossChainBridge.deriveRedeemTmpTokenAccountKey(
+                CROSS_CHAIN_BRIDGE_PID,
+                mint,
+                parseTokenTransferVaa(signedMsg).hash
+              )
+            );
           });
 
           it("Cannot Redeem Transfer Again", async function() {